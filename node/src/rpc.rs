@@ -8,11 +8,13 @@
 use std::sync::Arc;
 
 use jsonrpsee::RpcModule;
-use node_template_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use node_template_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Nonce, ProposalId};
+use sc_client_api::{BlockchainEvents, ProofProvider};
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
+use sp_core::traits::SpawnNamed;
 
 pub use sc_rpc_api::DenyUnsafe;
 
@@ -24,6 +26,8 @@ pub struct FullDeps<C, P> {
 	pub pool: Arc<P>,
 	/// Whether to deny unsafe calls
 	pub deny_unsafe: DenyUnsafe,
+	/// Executor to drive the subscriptions.
+	pub subscription_executor: Arc<dyn SpawnNamed>,
 }
 
 /// Instantiate all full RPC extensions.
@@ -33,20 +37,24 @@ pub fn create_full<C, P>(
 where
 	C: ProvideRuntimeApi<Block>,
 	C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
+	C: BlockchainEvents<Block> + ProofProvider<Block>,
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: pallet_proposal_rpc::ProposalRuntimeApi<Block, AccountId, ProposalId, BlockNumber, u16>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + 'static,
 {
+	use pallet_proposal_rpc::{Proposal, ProposalApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
 	let mut module = RpcModule::new(());
-	let FullDeps { client, pool, deny_unsafe } = deps;
+	let FullDeps { client, pool, deny_unsafe, subscription_executor } = deps;
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(Proposal::new(client, subscription_executor).into_rpc())?;
 
 	// Extend this RPC with a custom API by using the following syntax.
 	// `YourRpcStruct` should have a reference to a client, which is needed
@@ -62,3 +70,119 @@ where
 
 	Ok(module)
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::chain_spec;
+	use codec::Encode;
+	use node_template_runtime::{
+		opaque::Block, pallet_proposal, AccountId, BlockNumber, Hash, ProposalId, Runtime,
+		RuntimeApi,
+	};
+	use pallet_proposal::types::{Leading, ProposalStatus};
+	use pallet_proposal_proof::{proposal_key, verify_proposal, Error};
+	use pallet_proposal_rpc::{Proposal, ProposalApiServer};
+	use sc_client_api::in_mem;
+	use sc_service::{
+		client::{new_in_mem, ClientConfig},
+		GenesisBlockBuilder,
+	};
+	use sp_blockchain::HeaderBackend;
+	use sp_core::{sr25519, testing::TaskExecutor};
+	use sp_runtime::{traits::Header, BuildStorage, Storage};
+	use std::sync::Arc;
+
+	/// Development genesis with proposal 1 stored in it.
+	struct WithProposal(chain_spec::ChainSpec);
+
+	impl BuildStorage for WithProposal {
+		fn assimilate_storage(&self, storage: &mut Storage) -> Result<(), String> {
+			self.0.assimilate_storage(storage)?;
+			let proposal = pallet_proposal::ProposalOf::<Runtime> {
+				owner: chain_spec::get_account_id_from_seed::<sr25519::Public>("Alice"),
+				co_authors: Default::default(),
+				name: b"Treasury".to_vec().try_into().unwrap(),
+				description: Default::default(),
+				is_active: true,
+				voter_accounts: Default::default(),
+				in_support: Default::default(),
+				in_oppose: Default::default(),
+				status: ProposalStatus::VotingInProgress,
+				deadline: 100,
+				created_at: 0,
+				extensions: Default::default(),
+				anti_sniping: None,
+				auto_extended: 0,
+				candle_period: None,
+				tie_break: None,
+				owner_preference: None,
+				last_lead: Leading::Tie,
+				track: 0,
+				confirm_end: None,
+				curve_check: None,
+				revision: 0,
+			};
+			storage
+				.top
+				.insert(proposal_key::<Runtime>(1), proposal.encode());
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn proposal_proof_verifies_against_state_root() {
+		let backend = Arc::new(in_mem::Backend::<Block>::new());
+		let executor =
+			sc_executor::WasmExecutor::<sp_io::SubstrateHostFunctions>::builder().build();
+		let genesis = WithProposal(chain_spec::development_config().unwrap());
+		let genesis_block_builder =
+			GenesisBlockBuilder::new(&genesis, true, backend.clone(), executor.clone()).unwrap();
+		let client = Arc::new(
+			new_in_mem::<_, Block, _, RuntimeApi>(
+				backend,
+				executor,
+				genesis_block_builder,
+				None,
+				None,
+				Box::new(TaskExecutor::new()),
+				ClientConfig::default(),
+			)
+			.unwrap(),
+		);
+		let rpc = Proposal::<_, Block>::new(client.clone(), Arc::new(TaskExecutor::new()));
+		let proof = |proposal_id| {
+			ProposalApiServer::<Hash, ProposalId, AccountId, BlockNumber, u16>::proof(
+				&rpc,
+				proposal_id,
+				None,
+			)
+			.unwrap()
+		};
+
+		let genesis_hash = client.info().genesis_hash;
+		let state_root = *client.header(genesis_hash).unwrap().unwrap().state_root();
+
+		let response = proof(1);
+		assert_eq!(response.at, genesis_hash);
+		assert_eq!(response.key.0, proposal_key::<Runtime>(1));
+		let proven = response.proposal.unwrap();
+		assert_eq!(proven.name, "Treasury");
+		assert_eq!(proven.status, ProposalStatus::VotingInProgress);
+		let nodes: Vec<_> = response.proof.into_iter().map(|node| node.0).collect();
+		let proposal = verify_proposal::<Runtime>(&state_root, nodes.clone(), 1)
+			.unwrap()
+			.unwrap();
+		assert_eq!(proposal.name.into_inner(), b"Treasury".to_vec());
+		assert!(proposal.is_active);
+
+		// The proof does not hold up against another state root.
+		let result = verify_proposal::<Runtime>(&Hash::zero(), nodes, 1);
+		assert!(matches!(result, Err(Error::InvalidProof)));
+
+		// A proposal that does not exist is proven absent.
+		let response = proof(2);
+		assert!(response.proposal.is_none());
+		let nodes = response.proof.into_iter().map(|node| node.0).collect();
+		assert!(matches!(verify_proposal::<Runtime>(&state_root, nodes, 2), Ok(None)));
+	}
+}
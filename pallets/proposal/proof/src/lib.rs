@@ -0,0 +1,51 @@
+//! Verification of storage proofs of proposals, for clients that only trust a state root.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::DecodeAll;
+use pallet_proposal::{Config, ProposalOf, Proposals};
+use sp_core::Hasher;
+use sp_std::vec::Vec;
+use sp_trie::{LayoutV1, StorageProof};
+
+/// Reason a storage proof is rejected.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum Error {
+	/// The proof does not hold the key under the state root.
+	InvalidProof,
+	/// The value the proof holds for the key does not decode as the expected type.
+	InvalidValue,
+}
+
+/// Key proposal `proposal_id` is stored under in `Proposals`.
+pub fn proposal_key<T: Config>(proposal_id: T::ProposalId) -> Vec<u8> {
+	Proposals::<T>::hashed_key_for(proposal_id)
+}
+
+/// Value of type `V` stored under `key` in the state with root `state_root`, read from `proof`,
+/// the trie nodes of a storage read proof of `key`. Returns `None` if the proof shows nothing is
+/// stored under `key`.
+pub fn verify_value<H: Hasher, V: DecodeAll>(
+	state_root: &H::Out,
+	proof: Vec<Vec<u8>>,
+	key: &[u8],
+) -> Result<Option<V>, Error> {
+	let db = StorageProof::new(proof).into_memory_db::<H>();
+	let value = sp_trie::read_trie_value::<LayoutV1<H>, _>(&db, state_root, key, None, None)
+		.map_err(|_| Error::InvalidProof)?;
+
+	value
+		.map(|value| V::decode_all(&mut &value[..]).map_err(|_| Error::InvalidValue))
+		.transpose()
+}
+
+/// Proposal `proposal_id` in the state with root `state_root`, read from `proof`, the trie nodes
+/// of a storage read proof of its key. Returns `None` if the proof shows there is no such
+/// proposal.
+pub fn verify_proposal<T: Config>(
+	state_root: &T::Hash,
+	proof: Vec<Vec<u8>>,
+	proposal_id: T::ProposalId,
+) -> Result<Option<ProposalOf<T>>, Error> {
+	verify_value::<T::Hashing, _>(state_root, proof, &proposal_key::<T>(proposal_id))
+}
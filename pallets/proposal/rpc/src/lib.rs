@@ -0,0 +1,694 @@
+//! RPC interface for the proposal pallet.
+
+use std::{iter, sync::Arc};
+
+use codec::Codec;
+use futures::{future, stream, FutureExt, Stream, StreamExt};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{error::ErrorObject, ErrorObjectOwned},
+	PendingSubscriptionSink, SubscriptionMessage,
+};
+use pallet_proposal_proof::verify_value;
+use pallet_proposal_runtime_api::Proposal as StoredValue;
+use sc_client_api::{BlockchainEvents, ProofProvider};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{traits::SpawnNamed, Bytes, ConstU32};
+use sp_runtime::traits::{Block as BlockT, HashingFor, Header as HeaderT};
+
+pub use pallet_proposal_runtime_api::{
+	NameMatch, Page, ProposalApi as ProposalRuntimeApi, ProposalChange, ProposalDetails,
+	ProposalFilter, ProposalQuery, ProposalStatus, ProposalUpdate, Simulation, Tally, Vote,
+	VoterProof,
+};
+
+/// Proposal as returned over RPC, with its name and description decoded as UTF-8.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalInfo<ProposalId, AccountId, BlockNumber, TrackId> {
+	pub id: ProposalId,
+	pub owner: AccountId,
+	pub co_authors: Vec<AccountId>,
+	pub name: String,
+	pub description: String,
+	/// Track, and so community, the proposal was created on.
+	pub track: TrackId,
+	pub status: ProposalStatus,
+	pub is_active: bool,
+	pub created_at: BlockNumber,
+	pub deadline: BlockNumber,
+	pub revision: u32,
+	pub tally: Tally,
+}
+
+impl<ProposalId, AccountId, BlockNumber, TrackId>
+	ProposalInfo<ProposalId, AccountId, BlockNumber, TrackId>
+{
+	/// `details` of proposal `id`, with invalid UTF-8 in its name and description replaced.
+	fn new(id: ProposalId, details: ProposalDetails<AccountId, BlockNumber, TrackId>) -> Self {
+		Self {
+			id,
+			owner: details.owner,
+			co_authors: details.co_authors,
+			name: String::from_utf8_lossy(&details.name).into_owned(),
+			description: String::from_utf8_lossy(&details.description).into_owned(),
+			track: details.track,
+			status: details.status,
+			is_active: details.is_active,
+			created_at: details.created_at,
+			deadline: details.deadline,
+			revision: details.revision,
+			tally: details.tally,
+		}
+	}
+}
+
+/// Criteria of a proposal search. Unset criteria match any proposal.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchQuery<AccountId, BlockNumber> {
+	/// Text the name starts with. Cannot be given together with `name_contains`.
+	pub name_prefix: Option<String>,
+	/// Text the name contains.
+	pub name_contains: Option<String>,
+	pub owner: Option<AccountId>,
+	pub status: Option<ProposalStatus>,
+	/// Earliest block the proposal was created, or launched, at.
+	pub created_from: Option<BlockNumber>,
+	/// Latest block the proposal was created, or launched, at.
+	pub created_to: Option<BlockNumber>,
+}
+
+/// Page of search results.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchPage<ProposalId, AccountId, BlockNumber, TrackId> {
+	pub proposals: Vec<ProposalInfo<ProposalId, AccountId, BlockNumber, TrackId>>,
+	/// Cursor to pass to the next search to continue after this page, unless the search is
+	/// complete.
+	pub next: Option<Bytes>,
+}
+
+/// Limit of the bounded fields of a proposal read from a proof, which were checked when stored.
+type Unbounded = ConstU32<{ u32::MAX }>;
+
+/// Proposal as stored in `Proposals`.
+type StoredProposal<AccountId, BlockNumber, TrackId> = StoredValue<
+	AccountId,
+	BlockNumber,
+	TrackId,
+	Unbounded,
+	Unbounded,
+	Unbounded,
+	Unbounded,
+	Unbounded,
+	ProposalStatus,
+>;
+
+/// Proposal as read from a storage proof, with its name and description decoded as UTF-8.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProvenProposal<AccountId, BlockNumber, TrackId> {
+	pub owner: AccountId,
+	pub co_authors: Vec<AccountId>,
+	pub name: String,
+	pub description: String,
+	pub track: TrackId,
+	pub status: ProposalStatus,
+	pub is_active: bool,
+	pub created_at: BlockNumber,
+	pub deadline: BlockNumber,
+	pub revision: u32,
+	/// Number of votes in support.
+	pub support: u32,
+	/// Number of votes in opposition.
+	pub oppose: u32,
+}
+
+impl<AccountId, BlockNumber, TrackId> From<StoredProposal<AccountId, BlockNumber, TrackId>>
+	for ProvenProposal<AccountId, BlockNumber, TrackId>
+{
+	fn from(proposal: StoredProposal<AccountId, BlockNumber, TrackId>) -> Self {
+		let (support, oppose) = (proposal.in_support.len() as u32, proposal.in_oppose.len() as u32);
+		Self {
+			owner: proposal.owner,
+			co_authors: proposal.co_authors.into_inner(),
+			name: String::from_utf8_lossy(&proposal.name).into_owned(),
+			description: String::from_utf8_lossy(&proposal.description).into_owned(),
+			track: proposal.track,
+			status: proposal.status,
+			is_active: proposal.is_active,
+			created_at: proposal.created_at,
+			deadline: proposal.deadline,
+			revision: proposal.revision,
+			support,
+			oppose,
+		}
+	}
+}
+
+/// Storage read proof of a proposal in `Proposals`, with the proposal read from it.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalProof<BlockHash, AccountId, BlockNumber, TrackId> {
+	/// Hash of the block whose state root the proof is checked against.
+	pub at: BlockHash,
+	/// Storage key of the proposal.
+	pub key: Bytes,
+	/// The proposal read from `proof`, unless the proof shows it does not exist.
+	pub proposal: Option<ProvenProposal<AccountId, BlockNumber, TrackId>>,
+	/// Trie nodes proving the value stored under `key`.
+	pub proof: Vec<Bytes>,
+}
+
+/// Change made to a proposal, as pushed to subscribers.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalNotification<BlockHash, ProposalId, AccountId, TrackId> {
+	/// Hash of the imported block the change was made in.
+	pub block: BlockHash,
+	#[serde(flatten)]
+	pub update: ProposalUpdate<ProposalId, AccountId, TrackId>,
+}
+
+#[rpc(client, server)]
+pub trait ProposalApi<BlockHash, ProposalId, AccountId, BlockNumber, TrackId> {
+	/// A page of the proposals meeting `filter`, or of all proposals if it is not given.
+	#[method(name = "proposal_list")]
+	fn list(
+		&self,
+		filter: Option<ProposalFilter<AccountId, TrackId>>,
+		page: Option<Page>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<ProposalInfo<ProposalId, AccountId, BlockNumber, TrackId>>>;
+
+	/// A page of the active proposals.
+	#[method(name = "proposal_active")]
+	fn active(
+		&self,
+		page: Option<Page>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<ProposalInfo<ProposalId, AccountId, BlockNumber, TrackId>>>;
+
+	/// Proposal `proposal_id`, if it exists.
+	#[method(name = "proposal_get")]
+	fn proposal(
+		&self,
+		proposal_id: ProposalId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<ProposalInfo<ProposalId, AccountId, BlockNumber, TrackId>>>;
+
+	/// A page of the accounts that voted on proposal `proposal_id`, with how they voted.
+	#[method(name = "proposal_voters")]
+	fn voters(
+		&self,
+		proposal_id: ProposalId,
+		page: Option<Page>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Vec<(AccountId, Vote)>>>;
+
+	/// A page of the most recent proposals `who` voted on, newest first, with how they voted.
+	#[method(name = "proposal_votesOf")]
+	fn votes_of(
+		&self,
+		who: AccountId,
+		page: Option<Page>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(ProposalId, Vote)>>;
+
+	/// Storage read proof of proposal `proposal_id`, with the proposal read from it.
+	#[method(name = "proposal_proof")]
+	fn proof(
+		&self,
+		proposal_id: ProposalId,
+		at: Option<BlockHash>,
+	) -> RpcResult<ProposalProof<BlockHash, AccountId, BlockNumber, TrackId>>;
+
+	/// Tally of proposal `proposal_id` as `(block, support, oppose)` over its lifetime, oldest
+	/// first. Kept after the proposal closes, until it is archived.
+	#[method(name = "proposal_tallyCheckpoints")]
+	fn tally_checkpoints(
+		&self,
+		proposal_id: ProposalId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(BlockNumber, u32, u32)>>;
+
+	/// Merkle proof that `who` is part of the voter set proposal `proposal_id` committed to when
+	/// it closed, if it has closed and `who` voted on it.
+	#[method(name = "proposal_voterProof")]
+	fn voter_proof(
+		&self,
+		proposal_id: ProposalId,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<VoterProof<BlockHash>>>;
+
+	/// Up to `limit` proposals meeting `query`, continuing from `cursor` if given. Without a
+	/// `limit` a full page is returned; a `limit` of zero is rejected.
+	#[method(name = "proposal_search")]
+	fn search(
+		&self,
+		query: SearchQuery<AccountId, BlockNumber>,
+		cursor: Option<Bytes>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<SearchPage<ProposalId, AccountId, BlockNumber, TrackId>>;
+
+	/// Name of the error `who` voting `choice` on proposal `proposal_id` would fail with, if any.
+	#[method(name = "proposal_validateVote")]
+	fn validate_vote(
+		&self,
+		proposal_id: ProposalId,
+		who: AccountId,
+		choice: Vote,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<String>>;
+
+	/// Outcome proposal `proposal_id` would close with if `votes` were cast now. A candle ending
+	/// is decided on the tally as of `candle_block`, defaulting to the deadline.
+	#[method(name = "proposal_simulateVotes")]
+	fn simulate_votes(
+		&self,
+		proposal_id: ProposalId,
+		votes: Vec<(AccountId, Vote)>,
+		candle_block: Option<BlockNumber>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Simulation>;
+
+	/// Changes made to the proposals meeting `filter`, or to all proposals if it is not given, as
+	/// new best blocks are imported.
+	#[subscription(
+		name = "proposal_subscribeUpdates" => "proposal_update",
+		unsubscribe = "proposal_unsubscribeUpdates",
+		item = ProposalNotification<BlockHash, ProposalId, AccountId, TrackId>,
+	)]
+	fn subscribe_updates(&self, filter: Option<ProposalFilter<AccountId, TrackId>>);
+
+	/// Changes made to proposal `proposal_id` as new best blocks are imported.
+	#[subscription(
+		name = "proposal_subscribeProposal" => "proposal_update",
+		unsubscribe = "proposal_unsubscribeProposal",
+		item = ProposalNotification<BlockHash, ProposalId, AccountId, TrackId>,
+	)]
+	fn subscribe_proposal(&self, proposal_id: ProposalId);
+}
+
+/// Log target of this RPC api.
+const LOG_TARGET: &str = "rpc::proposal";
+
+/// Provides RPC methods to query proposals.
+pub struct Proposal<C, P> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	/// Executor driving the subscriptions.
+	executor: Arc<dyn SpawnNamed>,
+	_marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Proposal<C, P> {
+	/// Creates a new instance of the Proposal Rpc helper.
+	pub fn new(client: Arc<C>, executor: Arc<dyn SpawnNamed>) -> Self {
+		Self { client, executor, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// A hypothetical vote would fail.
+	InvalidVote,
+	/// The search query is malformed.
+	InvalidQuery,
+	/// The storage proof could not be created.
+	ProofError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::InvalidVote => 2,
+			Error::InvalidQuery => 3,
+			Error::ProofError => 4,
+		}
+	}
+}
+
+/// Page used when a request does not give one, holding as many results as the runtime returns in
+/// one page.
+const FIRST_PAGE: Page = Page::FIRST;
+
+fn runtime_error(error: impl ToString, desc: &'static str) -> ErrorObjectOwned {
+	ErrorObject::owned(Error::RuntimeError.into(), desc, Some(error.to_string()))
+}
+
+fn proof_error(error: impl ToString) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::ProofError.into(),
+		"Unable to prove proposal.",
+		Some(error.to_string()),
+	)
+}
+
+/// Accepts the `pending` subscription and pushes the items of `stream` to it until either ends.
+async fn pipe_from_stream<T: Serialize>(
+	pending: PendingSubscriptionSink,
+	mut stream: impl Stream<Item = T> + Unpin,
+) {
+	let Ok(sink) = pending.accept().await else { return };
+	while let Some(item) = stream.next().await {
+		let Ok(message) = SubscriptionMessage::from_json(&item) else { break };
+		if sink.send(message).await.is_err() {
+			break;
+		}
+	}
+}
+
+impl<C, Block, ProposalId, AccountId, BlockNumber, TrackId> Proposal<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockchainEvents<Block>,
+	C: ProofProvider<Block> + Send + Sync + 'static,
+	C::Api: ProposalRuntimeApi<Block, AccountId, ProposalId, BlockNumber, TrackId>,
+	ProposalId: Codec + Clone + PartialEq + Send + Sync + 'static,
+	ProposalId: Serialize + for<'de> Deserialize<'de>,
+	AccountId: Codec + Clone + PartialEq + Send + Sync + 'static,
+	AccountId: Serialize + for<'de> Deserialize<'de>,
+	BlockNumber: Codec + Clone + Send + Sync + 'static,
+	BlockNumber: Serialize + for<'de> Deserialize<'de>,
+	TrackId: Codec + Clone + PartialEq + Send + Sync + 'static,
+	TrackId: Serialize + for<'de> Deserialize<'de>,
+{
+	/// Pushes to `pending` the changes made to proposals in each new best block for which `keep`
+	/// holds.
+	fn subscribe(
+		&self,
+		pending: PendingSubscriptionSink,
+		keep: impl Fn(&ProposalUpdate<ProposalId, AccountId, TrackId>) -> bool + Send + 'static,
+	) {
+		let client = self.client.clone();
+		let notifications = self
+			.client
+			.import_notification_stream()
+			.filter(|block| future::ready(block.is_new_best))
+			.map(move |block| {
+				client
+					.runtime_api()
+					.updates(block.hash)
+					.map(|updates| (block.hash, updates))
+					.map_err(|error| {
+						log::error!(
+							target: LOG_TARGET,
+							"Failed to read proposal updates at {:?}: {}",
+							block.hash,
+							error,
+						)
+					})
+			})
+			// The subscription is closed once the updates of a block cannot be read.
+			.scan((), |_, updates| future::ready(updates.ok()))
+			.flat_map(move |(hash, updates)| {
+				let notifications: Vec<_> = updates
+					.into_iter()
+					.filter(|update| keep(update))
+					.map(|update| ProposalNotification { block: hash, update })
+					.collect();
+				stream::iter(notifications)
+			});
+
+		self.executor.spawn(
+			"proposal-rpc-subscription",
+			Some("rpc"),
+			pipe_from_stream(pending, notifications.boxed()).boxed(),
+		);
+	}
+}
+
+impl<C, Block, ProposalId, AccountId, BlockNumber, TrackId>
+	ProposalApiServer<<Block as BlockT>::Hash, ProposalId, AccountId, BlockNumber, TrackId>
+	for Proposal<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockchainEvents<Block>,
+	C: ProofProvider<Block> + Send + Sync + 'static,
+	C::Api: ProposalRuntimeApi<Block, AccountId, ProposalId, BlockNumber, TrackId>,
+	ProposalId: Codec + Clone + PartialEq + Send + Sync + 'static,
+	ProposalId: Serialize + for<'de> Deserialize<'de>,
+	AccountId: Codec + Clone + PartialEq + Send + Sync + 'static,
+	AccountId: Serialize + for<'de> Deserialize<'de>,
+	BlockNumber: Codec + Clone + Send + Sync + 'static,
+	BlockNumber: Serialize + for<'de> Deserialize<'de>,
+	TrackId: Codec + Clone + PartialEq + Send + Sync + 'static,
+	TrackId: Serialize + for<'de> Deserialize<'de>,
+{
+	fn list(
+		&self,
+		filter: Option<ProposalFilter<AccountId, TrackId>>,
+		page: Option<Page>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<ProposalInfo<ProposalId, AccountId, BlockNumber, TrackId>>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let proposals = api
+			.proposals(at_hash, filter.unwrap_or_default(), page.unwrap_or(FIRST_PAGE))
+			.map_err(|e| runtime_error(e, "Unable to query proposals."))?;
+		Ok(proposals
+			.into_iter()
+			.map(|(id, details)| ProposalInfo::new(id, details))
+			.collect())
+	}
+
+	fn active(
+		&self,
+		page: Option<Page>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<ProposalInfo<ProposalId, AccountId, BlockNumber, TrackId>>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let proposals = api
+			.active_proposals(at_hash, page.unwrap_or(FIRST_PAGE))
+			.map_err(|e| runtime_error(e, "Unable to query active proposals."))?;
+		Ok(proposals
+			.into_iter()
+			.map(|(id, details)| ProposalInfo::new(id, details))
+			.collect())
+	}
+
+	fn proposal(
+		&self,
+		proposal_id: ProposalId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<ProposalInfo<ProposalId, AccountId, BlockNumber, TrackId>>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let details = api
+			.proposal(at_hash, proposal_id.clone(), Page::default())
+			.map_err(|e| runtime_error(e, "Unable to query proposal."))?;
+		Ok(details.map(|details| ProposalInfo::new(proposal_id, details)))
+	}
+
+	fn voters(
+		&self,
+		proposal_id: ProposalId,
+		page: Option<Page>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Vec<(AccountId, Vote)>>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let details = api
+			.proposal(at_hash, proposal_id, page.unwrap_or(FIRST_PAGE))
+			.map_err(|e| runtime_error(e, "Unable to query proposal voters."))?;
+		Ok(details.map(|details| details.voters))
+	}
+
+	fn votes_of(
+		&self,
+		who: AccountId,
+		page: Option<Page>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(ProposalId, Vote)>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.votes_of(at_hash, who, page.unwrap_or(FIRST_PAGE))
+			.map_err(|e| runtime_error(e, "Unable to query votes of account."))
+	}
+
+	fn proof(
+		&self,
+		proposal_id: ProposalId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<ProposalProof<Block::Hash, AccountId, BlockNumber, TrackId>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let key = api
+			.proposal_key(at_hash, proposal_id)
+			.map_err(|e| runtime_error(e, "Unable to query proposal key."))?;
+		let header = self
+			.client
+			.header(at_hash)
+			.map_err(proof_error)?
+			.ok_or_else(|| proof_error("Unknown block."))?;
+		let proof: Vec<_> = self
+			.client
+			.read_proof(at_hash, &mut iter::once(key.as_slice()))
+			.map_err(proof_error)?
+			.into_iter_nodes()
+			.collect();
+
+		// The proposal is read from the proof itself, so it is only returned if the proof holds.
+		let proposal: Option<StoredProposal<AccountId, BlockNumber, TrackId>> =
+			verify_value::<HashingFor<Block>, _>(header.state_root(), proof.clone(), &key)
+				.map_err(|e| proof_error(format!("{e:?}")))?;
+
+		Ok(ProposalProof {
+			at: at_hash,
+			key: Bytes(key),
+			proposal: proposal.map(Into::into),
+			proof: proof.into_iter().map(Bytes).collect(),
+		})
+	}
+
+	fn tally_checkpoints(
+		&self,
+		proposal_id: ProposalId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(BlockNumber, u32, u32)>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.tally_checkpoints(at_hash, proposal_id)
+			.map_err(|e| runtime_error(e, "Unable to query tally checkpoints."))
+	}
+
+	fn voter_proof(
+		&self,
+		proposal_id: ProposalId,
+		who: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<VoterProof<Block::Hash>>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.voter_proof(at_hash, proposal_id, who)
+			.map_err(|e| runtime_error(e, "Unable to query voter proof."))
+	}
+
+	fn search(
+		&self,
+		query: SearchQuery<AccountId, BlockNumber>,
+		cursor: Option<Bytes>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<SearchPage<ProposalId, AccountId, BlockNumber, TrackId>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let name = match (query.name_prefix, query.name_contains) {
+			(Some(_), Some(_)) => {
+				return Err(ErrorObject::owned(
+					Error::InvalidQuery.into(),
+					"Only one of namePrefix and nameContains can be given.",
+					None::<()>,
+				))
+			},
+			(Some(prefix), None) => Some(NameMatch::Prefix(prefix.into_bytes())),
+			(None, Some(part)) => Some(NameMatch::Contains(part.into_bytes())),
+			(None, None) => None,
+		};
+		let query = ProposalQuery {
+			name,
+			owner: query.owner,
+			status: query.status,
+			created_from: query.created_from,
+			created_to: query.created_to,
+		};
+
+		let limit = match limit {
+			Some(0) => {
+				return Err(ErrorObject::owned(
+					Error::InvalidQuery.into(),
+					"The limit must be at least one.",
+					None::<()>,
+				))
+			},
+			Some(limit) => limit,
+			None => FIRST_PAGE.limit,
+		};
+
+		let result = api
+			.search(at_hash, query, cursor.map(|cursor| cursor.0), limit)
+			.map_err(|e| runtime_error(e, "Unable to search proposals."))?;
+		Ok(SearchPage {
+			proposals: result
+				.proposals
+				.into_iter()
+				.map(|(id, details)| ProposalInfo::new(id, details))
+				.collect(),
+			next: result.next.map(Bytes),
+		})
+	}
+
+	fn validate_vote(
+		&self,
+		proposal_id: ProposalId,
+		who: AccountId,
+		choice: Vote,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<String>> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let result = api
+			.validate_vote(at_hash, proposal_id, who, choice)
+			.map_err(|e| runtime_error(e, "Unable to validate vote."))?;
+		Ok(result
+			.err()
+			.map(|error| String::from_utf8_lossy(&error).into_owned()))
+	}
+
+	fn simulate_votes(
+		&self,
+		proposal_id: ProposalId,
+		votes: Vec<(AccountId, Vote)>,
+		candle_block: Option<BlockNumber>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Simulation> {
+		let api = self.client.runtime_api();
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.simulate_votes(at_hash, proposal_id, votes, candle_block)
+			.map_err(|e| runtime_error(e, "Unable to simulate votes."))?
+			.map_err(|error| {
+				ErrorObject::owned(
+					Error::InvalidVote.into(),
+					"Hypothetical vote would fail.",
+					Some(String::from_utf8_lossy(&error).into_owned()),
+				)
+			})
+	}
+
+	fn subscribe_updates(
+		&self,
+		pending: PendingSubscriptionSink,
+		filter: Option<ProposalFilter<AccountId, TrackId>>,
+	) {
+		let filter = filter.unwrap_or_default();
+		self.subscribe(pending, move |update| {
+			filter.matches(&update.status, &update.owner, &update.track)
+		});
+	}
+
+	fn subscribe_proposal(&self, pending: PendingSubscriptionSink, proposal_id: ProposalId) {
+		self.subscribe(pending, move |update| update.proposal_id == proposal_id);
+	}
+}
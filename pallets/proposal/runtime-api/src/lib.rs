@@ -0,0 +1,95 @@
+//! Runtime API definition for the proposal pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_runtime::traits::Block as BlockT;
+use sp_std::vec::Vec;
+
+pub use pallet_proposal::types::{
+	NameMatch, Page, Projection, Proposal, ProposalChange, ProposalDetails, ProposalFilter,
+	ProposalQuery, ProposalStatus, ProposalUpdate, SearchResult, Simulation, Statistics, Tally,
+	Vote, VoterProof,
+};
+
+sp_api::decl_runtime_apis! {
+	/// Queries on proposals and their tallies, without decoding raw storage.
+	pub trait ProposalApi<AccountId, ProposalId, BlockNumber, TrackId>
+	where
+		AccountId: Codec,
+		ProposalId: Codec,
+		BlockNumber: Codec,
+		TrackId: Codec,
+	{
+		/// Proposal `proposal_id` with its current tally and the given page of its voters.
+		fn proposal(
+			proposal_id: ProposalId,
+			voters: Page,
+		) -> Option<ProposalDetails<AccountId, BlockNumber, TrackId>>;
+
+		/// Key proposal `proposal_id` is stored under in `Proposals`, to request a storage proof of
+		/// it.
+		fn proposal_key(proposal_id: ProposalId) -> Vec<u8>;
+
+		/// A page of the active proposals with their current tally, without their voters.
+		fn active_proposals(
+			page: Page,
+		) -> Vec<(ProposalId, ProposalDetails<AccountId, BlockNumber, TrackId>)>;
+
+		/// A page of the proposals meeting `filter`, with their current tally, without their
+		/// voters.
+		fn proposals(
+			filter: ProposalFilter<AccountId, TrackId>,
+			page: Page,
+		) -> Vec<(ProposalId, ProposalDetails<AccountId, BlockNumber, TrackId>)>;
+
+		/// Up to `limit` proposals meeting `query`, continuing from `cursor` if given. A `limit`
+		/// of zero returns a full page.
+		fn search(
+			query: ProposalQuery<AccountId, BlockNumber>,
+			cursor: Option<Vec<u8>>,
+			limit: u32,
+		) -> SearchResult<ProposalId, AccountId, BlockNumber, TrackId>;
+
+		/// A page of the most recent proposals `who` voted on, newest first, with how they voted.
+		fn votes_of(who: AccountId, page: Page) -> Vec<(ProposalId, Vote)>;
+
+		/// A page of the active proposals with the outcome each of them would have if it closed
+		/// now.
+		fn projected_outcomes(page: Page) -> Vec<(ProposalId, Projection)>;
+
+		/// Checks whether `who` voting `choice` on proposal `proposal_id` would succeed, returning
+		/// the name of the error it would fail with otherwise.
+		fn validate_vote(
+			proposal_id: ProposalId,
+			who: AccountId,
+			choice: Vote,
+		) -> Result<(), Vec<u8>>;
+
+		/// Outcome proposal `proposal_id` would close with if `votes` were cast now, or the name of
+		/// the error the first failing vote would fail with. A candle ending is decided on the
+		/// tally as of `candle_block`, defaulting to the deadline.
+		fn simulate_votes(
+			proposal_id: ProposalId,
+			votes: Vec<(AccountId, Vote)>,
+			candle_block: Option<BlockNumber>,
+		) -> Result<Simulation, Vec<u8>>;
+
+		/// Tally of proposal `proposal_id` as `(block, support, oppose)` after the votes of the
+		/// blocks it was voted on, oldest first. Empty once the proposal has been archived.
+		fn tally_checkpoints(proposal_id: ProposalId) -> Vec<(BlockNumber, u32, u32)>;
+
+		/// Merkle proof that `who` is part of the voter set proposal `proposal_id` committed to
+		/// when it closed, if it has closed and `who` voted on it.
+		fn voter_proof(
+			proposal_id: ProposalId,
+			who: AccountId,
+		) -> Option<VoterProof<<Block as BlockT>::Hash>>;
+
+		/// Running statistics over all proposals.
+		fn statistics() -> Statistics;
+
+		/// Proposals created, voted on or closed in the block, with their state at the end of it.
+		fn updates() -> Vec<ProposalUpdate<ProposalId, AccountId, TrackId>>;
+	}
+}
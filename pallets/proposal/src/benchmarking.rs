@@ -5,9 +5,14 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as ProposalPallet;
 use frame_benchmarking::v2::*;
+use frame_support::traits::{
+	fungible::{Inspect, Mutate, MutateHold},
+	EnsureOrigin, Get, Hooks,
+};
 use frame_system::RawOrigin;
 
-use crate::types::Proposal;
+use crate::types::{Amendment, AntiSniping, Deposit, Invitation, Proposal, QueueEntry};
+use frame_support::sp_runtime::traits::One;
 
 pub fn add_proposal<T: Config>(caller: T::AccountId) {
 	let proposal_id = NextProposalId::<T>::get()
@@ -26,6 +31,7 @@ pub fn add_proposal<T: Config>(caller: T::AccountId) {
 
 	let new_proposal = Proposal {
 		owner: caller.clone(),
+		co_authors: Default::default(),
 		name: bounded_proposal_name,
 		description: bounded_proposal_description,
 		is_active: true,
@@ -33,9 +39,41 @@ pub fn add_proposal<T: Config>(caller: T::AccountId) {
 		in_support: bounded_account.clone(),
 		in_oppose: bounded_account.clone(),
 		status: ProposalStatus::VotingInProgress,
+		deadline: BLOCKS_PER_DAY.into(),
+		created_at: Zero::zero(),
+		extensions: Default::default(),
+		// A vote always lands inside the window, so `vote` measures the rescheduling path.
+		anti_sniping: Some(AntiSniping {
+			window: BLOCKS_PER_DAY.into(),
+			extension: One::one(),
+			max_extension: One::one(),
+		}),
+		auto_extended: Zero::zero(),
+		candle_period: None,
+		tie_break: None,
+		owner_preference: None,
+		last_lead: Default::default(),
+		track: T::Tracks::tracks()[0].0,
+		confirm_end: None,
+		curve_check: None,
+		revision: 0,
 	};
 
 	<Proposals<T>>::insert(proposal_id, &new_proposal);
+	<ProposalExpireTime<T>>::insert(new_proposal.deadline, proposal_id);
+}
+
+/// Record `count` votes in support of proposal `proposal_id`.
+pub fn add_voters<T: Config>(proposal_id: T::ProposalId, count: u32) {
+	Proposals::<T>::mutate(proposal_id, |proposal| {
+		if let Some(proposal) = proposal {
+			for index in 0..count {
+				let voter: T::AccountId = account("voter", index, 0);
+				let _ = proposal.voter_accounts.try_push(voter.clone());
+				let _ = proposal.in_support.try_push(voter);
+			}
+		}
+	});
 }
 
 #[benchmarks]
@@ -49,6 +87,9 @@ mod benchmarks {
 
 		let value = 1u32.into();
 		let caller: T::AccountId = whitelisted_caller();
+		let (track, info) = &T::Tracks::tracks()[0];
+		let funds = T::Currency::minimum_balance().saturating_add(info.submission_deposit);
+		T::Currency::set_balance(&caller, funds.saturating_mul(10u32.into()));
 
 		let proposal_name: Vec<u8> = "First proposal".into();
 		let bounded_proposal_name: BoundedVec<u8, <T as pallet::Config>::NameLimit> =
@@ -61,9 +102,16 @@ mod benchmarks {
 		#[extrinsic_call]
 		create_proposal(
 			RawOrigin::Signed(caller.clone()),
+			*track,
 			bounded_proposal_name,
 			bounded_proposal_description,
 			value,
+			None,
+			None,
+			None,
+			Default::default(),
+			Default::default(),
+			None,
 		);
 
 		assert_eq!(Proposals::<T>::get(proposal_id).unwrap().owner, caller);
@@ -80,10 +128,486 @@ mod benchmarks {
 
 		let voter: T::AccountId = account("sub", 1, 0);
 
+		// The oldest vote of the voter is dropped from a full index.
+		let older_vote = (proposal_id.increment().unwrap(), Vote::NO);
+		let mut votes = BoundedVec::new();
+		while votes.try_push(older_vote.clone()).is_ok() {}
+		VotesByAccount::<T>::insert(&voter, votes);
+
 		#[extrinsic_call]
 		vote(RawOrigin::Signed(voter.clone()), proposal_id, Vote::YES);
 
 		assert_eq!(Proposals::<T>::get(proposal_id).unwrap().owner, caller);
+		assert_eq!(VotesByAccount::<T>::get(&voter).last(), Some(&(proposal_id, Vote::YES)));
+	}
+
+	#[benchmark]
+	fn fast_track() -> Result<(), BenchmarkError> {
+		let proposal_id = NextProposalId::<T>::get()
+			.unwrap_or(T::ProposalId::initial_value().unwrap().increment().unwrap());
+
+		let caller: T::AccountId = whitelisted_caller();
+		let old_deadline: BlockNumberFor<T> = BLOCKS_PER_DAY.into();
+
+		add_proposal::<T>(caller.clone());
+
+		let origin =
+			T::FastTrackOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let voting_period = T::MinFastTrackPeriod::get();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, proposal_id, voting_period);
+
+		assert!(Proposals::<T>::get(proposal_id).unwrap().deadline < old_deadline);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn extend_deadline() -> Result<(), BenchmarkError> {
+		let proposal_id = NextProposalId::<T>::get()
+			.unwrap_or(T::ProposalId::initial_value().unwrap().increment().unwrap());
+
+		let caller: T::AccountId = whitelisted_caller();
+		let old_deadline: BlockNumberFor<T> = BLOCKS_PER_DAY.into();
+
+		add_proposal::<T>(caller.clone());
+
+		let origin =
+			T::ExtendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, proposal_id, BLOCKS_PER_DAY.into());
+
+		assert!(Proposals::<T>::get(proposal_id).unwrap().deadline > old_deadline);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_tie_preference() {
+		let proposal_id = NextProposalId::<T>::get()
+			.unwrap_or(T::ProposalId::initial_value().unwrap().increment().unwrap());
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		add_proposal::<T>(caller.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), proposal_id, Vote::YES);
+
+		assert_eq!(Proposals::<T>::get(proposal_id).unwrap().owner_preference, Some(Vote::YES));
+	}
+
+	#[benchmark]
+	fn place_decision_deposit() -> Result<(), BenchmarkError> {
+		let proposal_id = NextProposalId::<T>::get()
+			.unwrap_or(T::ProposalId::initial_value().unwrap().increment().unwrap());
+
+		let (track, info) = T::Tracks::tracks()
+			.iter()
+			.find(|(_, info)| !info.decision_deposit.is_zero())
+			.ok_or(BenchmarkError::Weightless)?;
+
+		let owner: T::AccountId = account("owner", 0, 0);
+		add_proposal::<T>(owner);
+		Proposals::<T>::mutate(proposal_id, |proposal| {
+			if let Some(proposal) = proposal {
+				proposal.status = ProposalStatus::Preparing;
+				proposal.track = *track;
+			}
+		});
+
+		let caller: T::AccountId = whitelisted_caller();
+		let funds = T::Currency::minimum_balance().saturating_add(info.decision_deposit);
+		T::Currency::set_balance(&caller, funds.saturating_mul(10u32.into()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), proposal_id);
+
+		assert_eq!(
+			Proposals::<T>::get(proposal_id).unwrap().status,
+			ProposalStatus::VotingInProgress
+		);
+		assert_eq!(DecisionDeposits::<T>::get(proposal_id).unwrap().who, caller);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn second() -> Result<(), BenchmarkError> {
+		let proposal_id = NextProposalId::<T>::get()
+			.unwrap_or(T::ProposalId::initial_value().unwrap().increment().unwrap());
+
+		// The second that reaches the threshold launches the proposal.
+		let threshold = T::SecondingThreshold::get();
+		if threshold.is_zero() || threshold > T::MaxSeconds::get() {
+			return Err(BenchmarkError::Weightless);
+		}
+
+		let owner: T::AccountId = account("owner", 0, 0);
+		add_proposal::<T>(owner);
+		Proposals::<T>::mutate(proposal_id, |proposal| {
+			if let Some(proposal) = proposal {
+				proposal.status = ProposalStatus::Queued;
+				proposal.is_active = false;
+			}
+		});
+
+		let amount = T::Currency::minimum_balance();
+		let mut seconds: BoundedVec<_, T::MaxSeconds> = Default::default();
+		for index in 1..threshold {
+			let seconder: T::AccountId = account("seconder", index, 0);
+			T::Currency::set_balance(&seconder, amount.saturating_mul(10u32.into()));
+			T::Currency::hold(&HoldReason::SecondingDeposit.into(), &seconder, amount)?;
+			seconds
+				.try_push(Deposit { who: seconder, amount })
+				.map_err(|_| BenchmarkError::Weightless)?;
+		}
+		let entry = QueueEntry { duration: 1, queued_at: Zero::zero(), seconds };
+		ProposalPallet::<T>::rank_queued(proposal_id, &entry)?;
+		SecondingQueue::<T>::insert(proposal_id, entry);
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::set_balance(&caller, amount.saturating_mul(10u32.into()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), proposal_id, Some(amount));
+
+		assert!(SecondingQueue::<T>::get(proposal_id).is_none());
+		assert!(Proposals::<T>::get(proposal_id).unwrap().is_active);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_co_authorship() -> Result<(), BenchmarkError> {
+		let proposal_id = NextProposalId::<T>::get()
+			.unwrap_or(T::ProposalId::initial_value().unwrap().increment().unwrap());
+
+		if T::MaxCoAuthors::get().is_zero() {
+			return Err(BenchmarkError::Weightless);
+		}
+
+		let owner: T::AccountId = account("owner", 0, 0);
+		add_proposal::<T>(owner);
+		Proposals::<T>::mutate(proposal_id, |proposal| {
+			if let Some(proposal) = proposal {
+				proposal.status = ProposalStatus::AwaitingCoAuthors;
+				proposal.is_active = false;
+			}
+		});
+
+		// The last pending co-author accepting opens the proposal.
+		let caller: T::AccountId = whitelisted_caller();
+		let mut invited: BoundedVec<_, T::MaxCoAuthors> = Default::default();
+		invited
+			.try_push(caller.clone())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		CoAuthorInvitations::<T>::insert(proposal_id, Invitation { duration: 1, invited });
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), proposal_id);
+
+		assert!(CoAuthorInvitations::<T>::get(proposal_id).is_none());
+		assert!(Proposals::<T>::get(proposal_id)
+			.unwrap()
+			.co_authors
+			.contains(&caller));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn propose_amendment() -> Result<(), BenchmarkError> {
+		let proposal_id = NextProposalId::<T>::get()
+			.unwrap_or(T::ProposalId::initial_value().unwrap().increment().unwrap());
+
+		// The amendment vote has to close before the proposal's deadline.
+		let deadline: BlockNumberFor<T> = BLOCKS_PER_DAY.into();
+		if T::AmendmentPeriod::get() >= deadline || T::MaxRevisions::get().is_zero() {
+			return Err(BenchmarkError::Weightless);
+		}
+
+		let owner: T::AccountId = whitelisted_caller();
+		add_proposal::<T>(owner.clone());
+
+		let description: Vec<u8> = "Amended description of proposal".into();
+		let bounded_description: BoundedVec<u8, <T as pallet::Config>::DescriptionLimit> =
+			description.try_into().unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner), proposal_id, bounded_description, true);
+
+		assert!(Amendments::<T>::contains_key(proposal_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn vote_amendment() {
+		let proposal_id = NextProposalId::<T>::get()
+			.unwrap_or(T::ProposalId::initial_value().unwrap().increment().unwrap());
+
+		let owner: T::AccountId = account("owner", 0, 0);
+		add_proposal::<T>(owner.clone());
+		Amendments::<T>::insert(
+			proposal_id,
+			Amendment {
+				proposer: owner,
+				description: Default::default(),
+				reset_votes: false,
+				in_support: Default::default(),
+				in_oppose: Default::default(),
+				end: One::one(),
+			},
+		);
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), proposal_id, Vote::YES);
+
+		assert!(Amendments::<T>::get(proposal_id)
+			.unwrap()
+			.has_voted(&caller));
+	}
+
+	#[benchmark]
+	fn edit_proposal() -> Result<(), BenchmarkError> {
+		let proposal_id = NextProposalId::<T>::get()
+			.unwrap_or(T::ProposalId::initial_value().unwrap().increment().unwrap());
+
+		if T::MaxRevisions::get().is_zero() {
+			return Err(BenchmarkError::Weightless);
+		}
+
+		let caller: T::AccountId = whitelisted_caller();
+		add_proposal::<T>(caller.clone());
+
+		let proposal_name: Vec<u8> = "Edited proposal".into();
+		let bounded_proposal_name: BoundedVec<u8, <T as pallet::Config>::NameLimit> =
+			proposal_name.try_into().unwrap();
+
+		let proposal_description: Vec<u8> = "Edited description of proposal".into();
+		let bounded_proposal_description: BoundedVec<u8, <T as pallet::Config>::DescriptionLimit> =
+			proposal_description.try_into().unwrap();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			proposal_id,
+			bounded_proposal_name,
+			bounded_proposal_description,
+		);
+
+		assert_eq!(Proposals::<T>::get(proposal_id).unwrap().revision, 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn archive_proposal() -> Result<(), BenchmarkError> {
+		let proposal_id = NextProposalId::<T>::get()
+			.unwrap_or(T::ProposalId::initial_value().unwrap().increment().unwrap());
+
+		if T::MaxCheckpoints::get().is_zero() {
+			return Err(BenchmarkError::Weightless);
+		}
+
+		let caller: T::AccountId = whitelisted_caller();
+		add_proposal::<T>(caller.clone());
+		Proposals::<T>::mutate(proposal_id, |proposal| {
+			if let Some(proposal) = proposal {
+				proposal.status = ProposalStatus::Accepted;
+				proposal.is_active = false;
+			}
+		});
+		// A full series, so the removal clears as much as it ever does.
+		let checkpoints: BoundedVec<_, T::MaxCheckpoints> = (0..T::MaxCheckpoints::get())
+			.map(|block| (block.into(), block, 0))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		TallyCheckpoints::<T>::insert(proposal_id, checkpoints);
+		let deadline: BlockNumberFor<T> = BLOCKS_PER_DAY.into();
+		frame_system::Pallet::<T>::set_block_number(
+			deadline.saturating_add(T::ArchivePeriod::get()),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), proposal_id);
+
+		assert!(!TallyCheckpoints::<T>::contains_key(proposal_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn close_proposal(v: Linear<0, { T::AccountLimit::get() }>) {
+		let proposal_id = NextProposalId::<T>::get()
+			.unwrap_or(T::ProposalId::initial_value().unwrap().increment().unwrap());
+
+		let owner: T::AccountId = account("owner", 0, 0);
+		add_proposal::<T>(owner);
+		add_voters::<T>(proposal_id, v);
+		let deadline: BlockNumberFor<T> = BLOCKS_PER_DAY.into();
+
+		#[block]
+		{
+			ProposalPallet::<T>::on_initialize(deadline);
+		}
+
+		assert!(!Proposals::<T>::get(proposal_id).unwrap().is_active);
+		assert!(VoterRoots::<T>::contains_key(proposal_id));
+	}
+
+	#[benchmark]
+	fn close_group(
+		p: Linear<1, { T::MaxGroupSize::get() }>,
+		v: Linear<0, { T::AccountLimit::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let group = NextProposalId::<T>::get()
+			.unwrap_or(T::ProposalId::initial_value().unwrap().increment().unwrap());
+
+		let owner: T::AccountId = account("owner", 0, 0);
+		add_proposal::<T>(owner);
+		let proposal = Proposals::<T>::get(group).ok_or(BenchmarkError::Weightless)?;
+
+		let mut members: BoundedVec<_, T::MaxGroupSize> = Default::default();
+		let mut proposal_id = group;
+		for _ in 0..p {
+			Proposals::<T>::insert(proposal_id, &proposal);
+			ProposalGroup::<T>::insert(proposal_id, group);
+			members
+				.try_push(proposal_id)
+				.map_err(|_| BenchmarkError::Weightless)?;
+			proposal_id = proposal_id.increment().ok_or(BenchmarkError::Weightless)?;
+		}
+		Groups::<T>::insert(group, members);
+
+		// The votes are all cast on the proposal the group was formed around.
+		add_voters::<T>(group, v);
+
+		#[block]
+		{
+			ProposalPallet::<T>::close_group(group, BLOCKS_PER_DAY.into());
+		}
+
+		assert!(Groups::<T>::get(group).is_empty());
+		assert!(!Proposals::<T>::get(group).unwrap().is_active);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn close_amendment(v: Linear<0, { T::AccountLimit::get() }>) {
+		let proposal_id = NextProposalId::<T>::get()
+			.unwrap_or(T::ProposalId::initial_value().unwrap().increment().unwrap());
+
+		let owner: T::AccountId = account("owner", 0, 0);
+		add_proposal::<T>(owner.clone());
+		add_voters::<T>(proposal_id, v);
+		for index in 0..v {
+			let voter: T::AccountId = account("voter", index, 0);
+			ProposalPallet::<T>::record_vote(&voter, proposal_id, Vote::YES);
+		}
+
+		// An accepted amendment resetting the votes revises the proposal.
+		let mut in_support: BoundedVec<_, T::AccountLimit> = Default::default();
+		for index in 0..T::MinAmendmentVotes::get() {
+			let _ = in_support.try_push(account("voter", index, 0));
+		}
+		let description: Vec<u8> = "Amended description of proposal".into();
+		Amendments::<T>::insert(
+			proposal_id,
+			Amendment {
+				proposer: owner,
+				description: description.try_into().unwrap(),
+				reset_votes: true,
+				in_support,
+				in_oppose: Default::default(),
+				end: One::one(),
+			},
+		);
+
+		#[block]
+		{
+			ProposalPallet::<T>::close_amendment(proposal_id);
+		}
+
+		assert!(Amendments::<T>::get(proposal_id).is_none());
+	}
+
+	#[benchmark]
+	fn launch_most_seconded(q: Linear<1, { T::MaxQueued::get() }>) -> Result<(), BenchmarkError> {
+		let first = NextProposalId::<T>::get()
+			.unwrap_or(T::ProposalId::initial_value().unwrap().increment().unwrap());
+
+		let owner: T::AccountId = account("owner", 0, 0);
+		add_proposal::<T>(owner);
+		let mut proposal = Proposals::<T>::get(first).ok_or(BenchmarkError::Weightless)?;
+		proposal.status = ProposalStatus::Queued;
+		proposal.is_active = false;
+
+		// Every queued proposal is fully seconded, the deposits of the launched one released.
+		let amount = T::Currency::minimum_balance();
+		let mut seconds: BoundedVec<_, T::MaxSeconds> = Default::default();
+		for index in 0..T::MaxSeconds::get() {
+			let seconder: T::AccountId = account("seconder", index, 0);
+			T::Currency::set_balance(&seconder, amount.saturating_mul((q + 10).into()));
+			seconds
+				.try_push(Deposit { who: seconder, amount })
+				.map_err(|_| BenchmarkError::Weightless)?;
+		}
+
+		let mut proposal_id = first;
+		for _ in 0..q {
+			Proposals::<T>::insert(proposal_id, &proposal);
+			for second in seconds.iter() {
+				T::Currency::hold(&HoldReason::SecondingDeposit.into(), &second.who, amount)?;
+			}
+			let entry =
+				QueueEntry { duration: 1, queued_at: Zero::zero(), seconds: seconds.clone() };
+			ProposalPallet::<T>::rank_queued(proposal_id, &entry)?;
+			SecondingQueue::<T>::insert(proposal_id, entry);
+			proposal_id = proposal_id.increment().ok_or(BenchmarkError::Weightless)?;
+		}
+
+		#[block]
+		{
+			ProposalPallet::<T>::launch_most_seconded();
+		}
+
+		assert_eq!(SecondingQueue::<T>::iter().count() as u32, q - 1);
+		assert_eq!(QueueRanking::<T>::decode_len().unwrap_or_default() as u32, q - 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_dependent() -> Result<(), BenchmarkError> {
+		let proposal_id = NextProposalId::<T>::get()
+			.unwrap_or(T::ProposalId::initial_value().unwrap().increment().unwrap());
+		let prerequisite = proposal_id.increment().ok_or(BenchmarkError::Weightless)?;
+
+		// A proposal still waiting on its co-authors has its invitation and expiry dropped too.
+		let owner: T::AccountId = account("owner", 0, 0);
+		add_proposal::<T>(owner.clone());
+		Proposals::<T>::mutate(proposal_id, |proposal| {
+			if let Some(proposal) = proposal {
+				proposal.status = ProposalStatus::AwaitingCoAuthors;
+				proposal.is_active = false;
+			}
+		});
+		CoAuthorInvitations::<T>::insert(
+			proposal_id,
+			Invitation { duration: 1, invited: Default::default() },
+		);
+
+		let amount = T::Currency::minimum_balance();
+		T::Currency::set_balance(&owner, amount.saturating_mul(10u32.into()));
+		T::Currency::hold(&HoldReason::SubmissionDeposit.into(), &owner, amount)?;
+		SubmissionDeposits::<T>::insert(proposal_id, Deposit { who: owner, amount });
+		PendingCancellations::<T>::insert(proposal_id, prerequisite);
+
+		#[block]
+		{
+			ProposalPallet::<T>::cancel_pending();
+		}
+
+		assert_eq!(Proposals::<T>::get(proposal_id).unwrap().status, ProposalStatus::Cancelled);
+		Ok(())
 	}
 
 	impl_benchmark_test_suite!(ProposalPallet, crate::mock::new_test_ext(), crate::mock::Test);
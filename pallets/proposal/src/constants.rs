@@ -6,3 +6,16 @@ pub const PROPOSAL_DURATION_LIMIT: u32 = 30;
 /// Blocks per day is a assumption of block generating by chain in 24 hours
 /// Assuming chain generating the blocks in every 6 second. 1 Block = 6 second
 pub const BLOCKS_PER_DAY: u32 = 14_400;
+
+/// The maximum number of results in a page returned by the runtime API.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// The maximum number of index entries a search through the runtime API scans in one call.
+pub const MAX_SEARCH_SCAN: u32 = 1_000;
+
+/// The maximum number of blocks probed for a free slot in a block-keyed schedule, such as the
+/// expiry slot after a proposal's deadline.
+pub const MAX_EXPIRY_PROBES: u32 = 64;
+
+/// The weight every vote counts with in the voter set committed to when a proposal closes.
+pub const VOTE_WEIGHT: u32 = 1;
@@ -0,0 +1,22 @@
+// Accounts voting on proposals.
+
+/// Accounts that can vote on proposals.
+pub trait ElectorateInfo<AccountId> {
+	/// Whether `who` can vote on proposals.
+	fn contains(who: &AccountId) -> bool;
+
+	/// Number of accounts that can vote, including the proposal owner, if the electorate is
+	/// fixed. Proposals are only closed before their deadline on a fixed electorate.
+	fn size() -> Option<u32>;
+}
+
+/// Every account can vote, so no outcome is decided before the deadline.
+impl<AccountId> ElectorateInfo<AccountId> for () {
+	fn contains(_who: &AccountId) -> bool {
+		true
+	}
+
+	fn size() -> Option<u32> {
+		None
+	}
+}
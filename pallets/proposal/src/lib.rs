@@ -1,14 +1,40 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
-mod types;
-use crate::types::{Proposal, ProposalStatus, Vote};
+pub mod electorate;
+pub mod migrations;
+pub mod tracks;
+pub mod types;
+use crate::electorate::ElectorateInfo;
+use crate::tracks::{TrackInfo, TracksInfo};
+use crate::types::{
+	Amendment, AntiSniping, Curve, DeadlineExtension, Dependency, Deposit, Invitation, Leading,
+	Page, Projection, Proposal, ProposalChange, ProposalDetails, ProposalFilter, ProposalQuery,
+	ProposalStatus, ProposalUpdate, QueueEntry, Revision, SearchResult, Simulation, Statistics,
+	Tally, TieBreak, Vote, VoterProof,
+};
 use frame_support::{dispatch::DispatchResultWithPostInfo, BoundedVec};
-use sp_std::vec::Vec;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_std::{boxed::Box, cmp::Reverse, vec::Vec};
 
 mod constants;
-use crate::constants::{BLOCKS_PER_DAY, PROPOSAL_DURATION_LIMIT};
-use frame_support::traits::Incrementable;
+use crate::constants::{
+	BLOCKS_PER_DAY, MAX_EXPIRY_PROBES, MAX_PAGE_SIZE, MAX_SEARCH_SCAN, PROPOSAL_DURATION_LIMIT,
+	VOTE_WEIGHT,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+	sp_runtime::{
+		traits::{Hash as HashT, One, Saturating, TrailingZeroInput, Zero},
+		DispatchError, Perbill, SaturatedConversion,
+	},
+	storage::{with_transaction, StoragePrefixedMap, TransactionOutcome},
+	traits::{
+		fungible::{self, MutateHold},
+		tokens::Precision,
+		Get, Incrementable, Randomness,
+	},
+};
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
@@ -21,6 +47,84 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+/// Balance of the currency used for proposal deposits.
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Identifier of a governance track.
+pub type TrackIdOf<T> =
+	<<T as Config>::Tracks as TracksInfo<<T as frame_system::Config>::AccountId, BalanceOf<T>>>::Id;
+
+/// Entry of a proposal in the seconding queue.
+pub type QueueEntryOf<T> = QueueEntry<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	BlockNumberFor<T>,
+	<T as Config>::MaxSeconds,
+>;
+
+/// Place of a proposal in the seconding queue: its identifier, the number of its seconds, the
+/// deposit backing them and the block it was queued at.
+pub type QueueRankOf<T> = (<T as Config>::ProposalId, u32, BalanceOf<T>, BlockNumberFor<T>);
+
+/// Pending co-author invitations of a proposal.
+pub type InvitationOf<T> =
+	Invitation<<T as frame_system::Config>::AccountId, <T as Config>::MaxCoAuthors>;
+
+/// Prerequisites a proposal is waiting on before it opens.
+pub type DependencyOf<T> = Dependency<<T as Config>::ProposalId, <T as Config>::MaxPrerequisites>;
+
+/// Amendment to an open proposal.
+pub type AmendmentOf<T> = Amendment<
+	<T as frame_system::Config>::AccountId,
+	BlockNumberFor<T>,
+	<T as Config>::DescriptionLimit,
+	<T as Config>::AccountLimit,
+>;
+
+/// Entry in the revision history of a proposal.
+pub type RevisionOf<T> = Revision<
+	<T as frame_system::Config>::AccountId,
+	BlockNumberFor<T>,
+	<T as frame_system::Config>::Hash,
+>;
+
+/// Proposal as returned by the runtime API.
+pub type ProposalDetailsOf<T> =
+	ProposalDetails<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, TrackIdOf<T>>;
+
+/// Criteria proposals are listed by in the runtime API.
+pub type ProposalFilterOf<T> = ProposalFilter<<T as frame_system::Config>::AccountId, TrackIdOf<T>>;
+
+/// Criteria proposals are searched by in the runtime API.
+pub type ProposalQueryOf<T> =
+	ProposalQuery<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+/// Page of search results, as returned by the runtime API.
+pub type SearchResultOf<T> = SearchResult<
+	<T as Config>::ProposalId,
+	<T as frame_system::Config>::AccountId,
+	BlockNumberFor<T>,
+	TrackIdOf<T>,
+>;
+
+/// Change made to a proposal in a block, as returned by the runtime API.
+pub type ProposalUpdateOf<T> =
+	ProposalUpdate<<T as Config>::ProposalId, <T as frame_system::Config>::AccountId, TrackIdOf<T>>;
+
+/// Proposal type as stored by this pallet.
+pub type ProposalOf<T> = Proposal<
+	<T as frame_system::Config>::AccountId,
+	BlockNumberFor<T>,
+	TrackIdOf<T>,
+	<T as Config>::NameLimit,
+	<T as Config>::DescriptionLimit,
+	<T as Config>::AccountLimit,
+	<T as Config>::MaxExtensions,
+	<T as Config>::MaxCoAuthors,
+	ProposalStatus,
+>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -49,36 +153,359 @@ pub mod pallet {
 		#[pallet::constant]
 		type AccountLimit: Get<u32>;
 
+		/// Origin that can shorten the voting period of an active proposal.
+		type FastTrackOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The minimum number of blocks a fast-tracked proposal stays open for voting.
+		#[pallet::constant]
+		type MinFastTrackPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Origin that can extend the deadline of an active proposal any number of times.
+		type ExtendOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum number of deadline extensions recorded on a proposal.
+		#[pallet::constant]
+		type MaxExtensions: Get<u32>;
+
+		/// Source of randomness for drawing the close of candle-ending proposals.
+		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+		/// Accounts that can vote on proposals. On a fixed electorate, proposals are closed as
+		/// soon as their outcome is decided.
+		type Electorate: ElectorateInfo<Self::AccountId>;
+
+		/// How ties are decided, unless a proposal overrides it.
+		type TieBreak: Get<TieBreak>;
+
+		/// Approval threshold over the lifetime of a proposal, in place of the fixed threshold
+		/// of its track. Proposals pass as soon as their tally beats the curves.
+		type ApprovalCurve: Get<Option<Curve>>;
+
+		/// Minimum turnout over the lifetime of a proposal, in place of the fixed minimum of its
+		/// track. Proposals pass as soon as their tally beats the curves.
+		type SupportCurve: Get<Option<Curve>>;
+
+		/// Currency used for proposal deposits.
+		type Currency: fungible::Mutate<Self::AccountId>
+			+ fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+
+		/// Number of seconds that launches a queued proposal into voting. Zero disables the
+		/// seconding queue, so that proposals start voting when they are created.
+		#[pallet::constant]
+		type SecondingThreshold: Get<u32>;
+
+		/// The maximum number of seconds on a queued proposal.
+		#[pallet::constant]
+		type MaxSeconds: Get<u32>;
+
+		/// Number of blocks between launches of the most-seconded queued proposal.
+		#[pallet::constant]
+		type LaunchPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks a proposal waits in the seconding queue before it expires.
+		#[pallet::constant]
+		type QueuePeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of co-authors of a proposal.
+		#[pallet::constant]
+		type MaxCoAuthors: Get<u32>;
+
+		/// Number of blocks invited co-authors have to accept before the proposal expires.
+		#[pallet::constant]
+		type InvitationPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks the vote on an amendment lasts.
+		#[pallet::constant]
+		type AmendmentPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The minimum number of votes an amendment needs to be accepted.
+		#[pallet::constant]
+		type MinAmendmentVotes: Get<u32>;
+
+		/// The maximum number of revisions kept in the history of a proposal.
+		#[pallet::constant]
+		type MaxRevisions: Get<u32>;
+
+		/// The maximum number of prerequisites of a proposal.
+		#[pallet::constant]
+		type MaxPrerequisites: Get<u32>;
+
+		/// The maximum number of proposals that can depend on a single proposal.
+		#[pallet::constant]
+		type MaxDependents: Get<u32>;
+
+		/// The maximum number of proposals competing in a group.
+		#[pallet::constant]
+		type MaxGroupSize: Get<u32>;
+
+		/// The maximum number of votes kept per account in `VotesByAccount`. Older votes are
+		/// dropped from the index as newer ones are cast.
+		#[pallet::constant]
+		type MaxVotesPerAccount: Get<u32>;
+
+		/// The maximum number of tally checkpoints kept per proposal. A full series is thinned
+		/// to every other checkpoint before the next one is added.
+		#[pallet::constant]
+		type MaxCheckpoints: Get<u32>;
+
+		/// The number of blocks past its deadline after which a closed proposal can be archived,
+		/// removing its tally checkpoints.
+		#[pallet::constant]
+		type ArchivePeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of proposals waiting in the seconding queue.
+		#[pallet::constant]
+		type MaxQueued: Get<u32>;
+
+		/// The maximum number of proposals cancelled in a block as one of their prerequisites was
+		/// not accepted. The others are cancelled in the blocks after.
+		#[pallet::constant]
+		type MaxCancellations: Get<u32>;
+
+		/// Governance tracks proposals are created on.
+		type Tracks: TracksInfo<
+			Self::AccountId,
+			BalanceOf<Self>,
+			RuntimeOrigin = <Self as frame_system::Config>::RuntimeOrigin,
+		>;
+
 		// Weight information
 		type WeightInfo: WeightInfo;
 	}
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// Deposit for creating a proposal.
+		SubmissionDeposit,
+		/// Deposit for moving a proposal from preparing to deciding.
+		DecisionDeposit,
+		/// Deposit backing the second of a queued proposal.
+		SecondingDeposit,
+	}
+
 	/// Store new proposal with a unique proposal id for a particular community
 	#[pallet::storage]
 	#[pallet::getter(fn proposals)]
-	pub type Proposals<T: Config> = StorageMap<
+	pub type Proposals<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ProposalId, ProposalOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_expire)]
+	pub type ProposalExpireTime<T: Config> =
+		StorageMap<_, Identity, BlockNumberFor<T>, T::ProposalId, OptionQuery>;
+
+	/// Running tallies of candle-ending proposals as `(support, oppose)`, as of the end of each
+	/// block of the ending period in which a vote was cast.
+	#[pallet::storage]
+	#[pallet::getter(fn candle_checkpoints)]
+	pub type CandleCheckpoints<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		T::ProposalId,
-		Proposal<
-			T::AccountId,
-			<T as pallet::Config>::NameLimit,
-			<T as Config>::DescriptionLimit,
-			T::AccountLimit,
-			ProposalStatus,
-		>,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		(u32, u32),
 		OptionQuery,
 	>;
 
+	/// Deposit held from the owner of each open proposal.
 	#[pallet::storage]
-	#[pallet::getter(fn proposal_expire)]
-	pub type ProposalExpireTime<T: Config> =
+	#[pallet::getter(fn submission_deposit)]
+	pub type SubmissionDeposits<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::ProposalId,
+		Deposit<T::AccountId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// Deposit placed for each proposal that left the preparing phase, held until it closes.
+	#[pallet::storage]
+	#[pallet::getter(fn decision_deposit)]
+	pub type DecisionDeposits<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::ProposalId,
+		Deposit<T::AccountId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// Proposal whose confirmation period ends on each block.
+	#[pallet::storage]
+	#[pallet::getter(fn confirmation_end)]
+	pub type ConfirmationEnd<T: Config> =
+		StorageMap<_, Identity, BlockNumberFor<T>, T::ProposalId, OptionQuery>;
+
+	/// Proposal to check against the approval and support curves on each block, as its tally
+	/// beats the curves from that block on.
+	#[pallet::storage]
+	#[pallet::getter(fn curve_check)]
+	pub type CurveCheck<T: Config> =
+		StorageMap<_, Identity, BlockNumberFor<T>, T::ProposalId, OptionQuery>;
+
+	/// Proposals waiting in the seconding queue.
+	#[pallet::storage]
+	#[pallet::getter(fn queued)]
+	pub type SecondingQueue<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ProposalId, QueueEntryOf<T>, OptionQuery>;
+
+	/// Proposals in the seconding queue, most seconded first. Ties go to the proposal backed
+	/// with the larger deposit, then to the one queued first.
+	#[pallet::storage]
+	#[pallet::getter(fn queue_ranking)]
+	pub type QueueRanking<T: Config> =
+		StorageValue<_, BoundedVec<QueueRankOf<T>, T::MaxQueued>, ValueQuery>;
+
+	/// Co-authors each proposal is waiting on before it opens.
+	#[pallet::storage]
+	#[pallet::getter(fn co_author_invitations)]
+	pub type CoAuthorInvitations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ProposalId, InvitationOf<T>, OptionQuery>;
+
+	/// Prerequisites each proposal is waiting on before it opens.
+	#[pallet::storage]
+	#[pallet::getter(fn prerequisites)]
+	pub type Prerequisites<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ProposalId, DependencyOf<T>, OptionQuery>;
+
+	/// Proposals waiting on each proposal to be accepted.
+	#[pallet::storage]
+	#[pallet::getter(fn dependents)]
+	pub type Dependents<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::ProposalId,
+		BoundedVec<T::ProposalId, T::MaxDependents>,
+		ValueQuery,
+	>;
+
+	/// Proposals waiting to be cancelled, with the prerequisite that was not accepted.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_cancellation)]
+	pub type PendingCancellations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ProposalId, T::ProposalId, OptionQuery>;
+
+	/// Proposals competing in each group, keyed by the proposal the group was formed around.
+	#[pallet::storage]
+	#[pallet::getter(fn group)]
+	pub type Groups<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::ProposalId,
+		BoundedVec<T::ProposalId, T::MaxGroupSize>,
+		ValueQuery,
+	>;
+
+	/// Group each competing proposal belongs to.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_group)]
+	pub type ProposalGroup<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ProposalId, T::ProposalId, OptionQuery>;
+
+	/// Amendment being voted on for each proposal.
+	#[pallet::storage]
+	#[pallet::getter(fn amendment)]
+	pub type Amendments<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ProposalId, AmendmentOf<T>, OptionQuery>;
+
+	/// Proposal whose amendment vote closes on each block.
+	#[pallet::storage]
+	#[pallet::getter(fn amendment_end)]
+	pub type AmendmentEnd<T: Config> =
 		StorageMap<_, Identity, BlockNumberFor<T>, T::ProposalId, OptionQuery>;
 
+	/// Revision history of each proposal, oldest first.
+	#[pallet::storage]
+	#[pallet::getter(fn revisions)]
+	pub type Revisions<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::ProposalId,
+		BoundedVec<RevisionOf<T>, T::MaxRevisions>,
+		ValueQuery,
+	>;
+
+	/// Proposals created by each account.
+	#[pallet::storage]
+	pub type ProposalsByOwner<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::ProposalId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Proposals with each status.
+	#[pallet::storage]
+	pub type ProposalsByStatus<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ProposalStatus,
+		Twox64Concat,
+		T::ProposalId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Proposals by the block they were created, or launched, at. The block is keyed as its
+	/// unhashed big-endian bytes so that proposals iterate in the order of that block.
+	#[pallet::storage]
+	pub type ProposalsByCreation<T: Config> =
+		StorageDoubleMap<_, Identity, [u8; 8], Twox64Concat, T::ProposalId, (), OptionQuery>;
+
+	/// Most recent votes of each account, oldest first.
+	#[pallet::storage]
+	#[pallet::getter(fn votes_by_account)]
+	pub type VotesByAccount<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(T::ProposalId, Vote), T::MaxVotesPerAccount>,
+		ValueQuery,
+	>;
+
+	/// Tally of each proposal, as `(block, support, oppose)`, after the votes of the blocks it was
+	/// voted on, oldest first. Kept after the proposal closes, until it is archived.
+	#[pallet::storage]
+	#[pallet::getter(fn tally_checkpoints)]
+	pub type TallyCheckpoints<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::ProposalId,
+		BoundedVec<(BlockNumberFor<T>, u32, u32), T::MaxCheckpoints>,
+		ValueQuery,
+	>;
+
+	/// Merkle root over the votes of each closed proposal, committing to its final voter set.
+	#[pallet::storage]
+	#[pallet::getter(fn voter_root)]
+	pub type VoterRoots<T: Config> = StorageMap<_, Blake2_128Concat, T::ProposalId, T::Hash>;
+
+	/// Running statistics over all proposals.
+	#[pallet::storage]
+	#[pallet::getter(fn statistics)]
+	pub type ProposalStatistics<T: Config> = StorageValue<_, Statistics, ValueQuery>;
+
+	/// Number of open proposals on each track.
+	#[pallet::storage]
+	#[pallet::getter(fn active_proposal_count)]
+	pub type ActiveProposalCount<T: Config> =
+		StorageMap<_, Twox64Concat, TrackIdOf<T>, u32, ValueQuery>;
+
 	/// Stores the `ProposalId` that is going to be used for the next proposal.
 	/// This gets incremented whenever a new proposal is created.
 	#[pallet::storage]
@@ -91,8 +518,57 @@ pub mod pallet {
 		CreatedProposal(T::ProposalId),
 		/// Submitted Proposal [Proposal Id]
 		VoteCasted(T::ProposalId),
-		/// Proposal closed [Proposal Id]
-		ProposalClosed(T::ProposalId),
+		/// Proposal closed [Proposal Id, Status, Tied, Voter Root]
+		ProposalClosed(T::ProposalId, ProposalStatus, bool, T::Hash),
+		/// Proposal fast-tracked [Proposal Id, Old Deadline, New Deadline]
+		ProposalFastTracked(T::ProposalId, BlockNumberFor<T>, BlockNumberFor<T>),
+		/// Proposal deadline extended [Proposal Id, Old Deadline, New Deadline]
+		ProposalDeadlineExtended(T::ProposalId, BlockNumberFor<T>, BlockNumberFor<T>),
+		/// Proposal deadline pushed back by a late vote [Proposal Id, Old Deadline, New Deadline]
+		ProposalAutoExtended(T::ProposalId, BlockNumberFor<T>, BlockNumberFor<T>),
+		/// Candle-ending proposal closed as of a randomly drawn block [Proposal Id, Candle Block]
+		CandleEnded(T::ProposalId, BlockNumberFor<T>),
+		/// Proposal closed before its deadline as the outcome can no longer change
+		/// [Proposal Id, Status, Tied]
+		ProposalDecidedEarly(T::ProposalId, ProposalStatus, bool),
+		/// Proposal owner set their tie preference [Proposal Id, Preference]
+		TiePreferenceSet(T::ProposalId, Vote),
+		/// Decision deposit placed, the proposal is now being decided
+		/// [Proposal Id, Depositor, Amount]
+		DecisionDepositPlaced(T::ProposalId, T::AccountId, BalanceOf<T>),
+		/// Proposal started passing [Proposal Id, Confirmation End]
+		ConfirmStarted(T::ProposalId, BlockNumberFor<T>),
+		/// Proposal stopped passing before its confirmation period ended [Proposal Id]
+		ConfirmAborted(T::ProposalId),
+		/// Proposal accepted at the end of its confirmation period [Proposal Id]
+		ProposalConfirmed(T::ProposalId),
+		/// Proposal accepted before its deadline as its tally beat the approval and support
+		/// curves [Proposal Id]
+		ProposalPassedEarly(T::ProposalId),
+		/// Queued proposal seconded [Proposal Id, Seconder, Deposit]
+		Seconded(T::ProposalId, T::AccountId, BalanceOf<T>),
+		/// Queued proposal moved into voting [Proposal Id, Deadline]
+		ProposalLaunched(T::ProposalId, BlockNumberFor<T>),
+		/// Invited account accepted to co-author a proposal [Proposal Id, Co-author]
+		CoAuthorAccepted(T::ProposalId, T::AccountId),
+		/// Amendment submitted to a proposal [Proposal Id, Proposer, Vote End]
+		AmendmentProposed(T::ProposalId, T::AccountId, BlockNumberFor<T>),
+		/// Vote cast on the amendment of a proposal [Proposal Id, Voter, Vote]
+		AmendmentVoteCast(T::ProposalId, T::AccountId, Vote),
+		/// Vote on the amendment of a proposal closed [Proposal Id, Accepted]
+		AmendmentClosed(T::ProposalId, bool),
+		/// Proposal description replaced by a new revision
+		/// [Proposal Id, Revision, Content Hash, Votes Reset]
+		ProposalRevised(T::ProposalId, u32, T::Hash, bool),
+		/// Proposal edited by its authors before any vote [Proposal Id, Revision, Content Hash]
+		ProposalEdited(T::ProposalId, u32, T::Hash),
+		/// Proposal cancelled as one of its prerequisites was not accepted
+		/// [Proposal Id, Prerequisite Id]
+		DependencyFailed(T::ProposalId, T::ProposalId),
+		/// Proposal joined a group of competing proposals [Proposal Id, Group Id, Deadline]
+		JoinedGroup(T::ProposalId, T::ProposalId, BlockNumberFor<T>),
+		/// Tally checkpoints of a closed proposal removed [Proposal Id]
+		ProposalArchived(T::ProposalId),
 	}
 
 	#[pallet::error]
@@ -111,43 +587,173 @@ pub mod pallet {
 		InvalidProposalDuration,
 		/// Proposal owner cannot vote on proposal.
 		OwnerCannotVote,
+		/// Account is not in the electorate.
+		NotInElectorate,
 		/// If creation of new bounded vector is not possible
 		CannotBeBounded,
+		/// Fast-track voting period is below `MinFastTrackPeriod` or zero.
+		FastTrackPeriodTooShort,
+		/// Fast-track would not bring the deadline forward.
+		DeadlineNotShortened,
+		/// Deadline extension must be at least one block.
+		InvalidExtension,
+		/// Extended deadline would exceed the maximum duration of the track.
+		ExtensionBeyondLimit,
+		/// Only the proposal owner can do this.
+		NotProposalOwner,
+		/// Proposal owner has already extended the deadline once.
+		OwnerExtensionUsed,
+		/// No more deadline extensions can be recorded on the proposal.
+		TooManyExtensions,
+		/// Anti-sniping window and extension must be at least one block, and the proposal
+		/// duration plus the maximum extension must fit in the track's maximum duration.
+		InvalidAntiSniping,
+		/// Candle ending period must be at least one block and within the proposal duration.
+		InvalidCandlePeriod,
+		/// A proposal cannot have both anti-sniping and a candle ending.
+		IncompatibleEnding,
+		/// Track does not exist.
+		BadTrack,
+		/// Track already has its maximum number of open proposals.
+		TrackFull,
+		/// No free block to close the proposal at within `MAX_EXPIRY_PROBES` blocks of its
+		/// deadline.
+		NoExpirySlot,
+		/// No free block to close the amendment vote at within `MAX_EXPIRY_PROBES` blocks of the
+		/// end of the amendment period.
+		NoAmendmentSlot,
+		/// Proposal is not waiting for a decision deposit.
+		NotPreparing,
+		/// Proposal is not in the seconding queue.
+		NotQueued,
+		/// Proposal owner cannot second their own proposal.
+		OwnerCannotSecond,
+		/// Account has already seconded the proposal.
+		AlreadySeconded,
+		/// No more seconds can be recorded on the proposal.
+		TooManySeconds,
+		/// Co-authors must be distinct accounts other than the proposal owner.
+		InvalidCoAuthors,
+		/// Account is not invited to co-author the proposal.
+		NotInvited,
+		/// Proposal already has an amendment being voted on.
+		AmendmentPending,
+		/// Proposal has no amendment being voted on.
+		NoAmendment,
+		/// Vote on the amendment would not close before the proposal's deadline.
+		AmendmentTooLate,
+		/// No more revisions can be recorded on the proposal.
+		TooManyRevisions,
+		/// Proposal can no longer be edited as votes have been cast on it.
+		ProposalHasVotes,
+		/// Prerequisites must be distinct proposals that can still be accepted.
+		InvalidPrerequisite,
+		/// No more proposals can depend on the prerequisite.
+		TooManyDependents,
+		/// Proposal to compete with is not open or does not have a fixed deadline.
+		InvalidGroup,
+		/// A competing proposal must open right away without anti-sniping or a candle ending.
+		IncompatibleGroup,
+		/// No more proposals can compete in the group.
+		GroupFull,
+		/// The deadline of a competing proposal is fixed by its group.
+		DeadlineFixedByGroup,
+		/// Proposal has not closed yet.
+		ProposalNotClosed,
+		/// Proposal cannot be archived before `ArchivePeriod` blocks past its deadline.
+		ArchivePeriodNotOver,
+		/// Proposal has no tally checkpoints left to remove.
+		NothingToArchive,
+		/// Seconding queue already holds `MaxQueued` proposals.
+		QueueFull,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+			let mut weight = T::DbWeight::get().reads(4);
+
+			// A proposal that kept passing for its whole confirmation period is accepted.
+			if let Some(proposal_id) = ConfirmationEnd::<T>::take(block_number) {
+				weight.saturating_accrue(Self::confirm_proposal(proposal_id, block_number));
+			}
+
+			// A proposal whose tally now beats the approval and support curves passes.
+			if let Some(proposal_id) = CurveCheck::<T>::take(block_number) {
+				weight.saturating_accrue(Self::check_curves(proposal_id, block_number));
+			}
+
+			// An amendment whose vote ended is applied to its proposal if it was accepted.
+			if let Some(proposal_id) = AmendmentEnd::<T>::take(block_number) {
+				weight.saturating_accrue(Self::close_amendment(proposal_id));
+			}
+
+			// The most-seconded queued proposal moves into voting once every launch period.
+			let launch_period = T::LaunchPeriod::get();
+			if !launch_period.is_zero() && (block_number % launch_period).is_zero() {
+				weight.saturating_accrue(Self::launch_most_seconded());
+			}
+
 			let option_proposal_expire = ProposalExpireTime::<T>::get(block_number);
 
+			// Competing proposals close together with the proposal their group was formed around.
+			let expiring_group = option_proposal_expire.and_then(ProposalGroup::<T>::get);
+
             // There is any proposal deadline ending on this block then this will Announce the Result.
-			if let Some(proposal_id) = option_proposal_expire {
+			if let Some(group) = expiring_group {
+				weight.saturating_accrue(Self::close_group(group, block_number));
+			} else if let Some(proposal_id) = option_proposal_expire {
 				Proposals::<T>::try_mutate(proposal_id, |proposal_detail| -> DispatchResult {
 					let proposal_data = proposal_detail
 						.as_mut()
 						.ok_or(Error::<T>::ProposalDoesNotExist)?;
 
-					// fetching the vote information of the proposal.
-					let support = &proposal_data.in_support.len();
-					let oppose = &proposal_data.in_oppose.len();
-
-					// Inserting the proposal result according to the voting.
-					// If support is more than the oppose.
-					if support > oppose {
-						proposal_data.status = ProposalStatus::Accepted;
-					} else {
-						proposal_data.status = ProposalStatus::Rejected;
+					// Inserting the proposal result according to the voting. A proposal that
+					// never got a decision deposit, its co-authors or out of the queue is
+					// rejected.
+					let (status, tied) = match proposal_data.status {
+						ProposalStatus::Preparing => (ProposalStatus::Rejected, false),
+						ProposalStatus::AwaitingCoAuthors => {
+							CoAuthorInvitations::<T>::remove(proposal_id);
+							(ProposalStatus::Rejected, false)
+						},
+						ProposalStatus::Queued => {
+							if let Some(entry) = SecondingQueue::<T>::take(proposal_id) {
+								Self::unrank_queued(proposal_id);
+								Self::release_seconds(&entry);
+							}
+							(ProposalStatus::Rejected, false)
+						},
+						_ => {
+							// fetching the vote information of the proposal.
+							let (support, oppose) = Self::closing_tally(proposal_id, proposal_data);
+							Self::outcome(proposal_id, proposal_data, support, oppose, block_number)
+						},
 					};
+					Self::set_status(proposal_id, proposal_data, status.clone());
 
 					proposal_data.is_active = false;
+					Self::finish_proposal(proposal_id, proposal_data);
 
-					Self::deposit_event(Event::<T>::ProposalClosed(proposal_id));
+					let root = Self::commit_voters(proposal_id, proposal_data);
+					Self::deposit_event(Event::<T>::ProposalClosed(
+						proposal_id,
+						status,
+						tied,
+						root,
+					));
 
 					Ok(())
 				})
 				.expect("Proposal not found");
+				let voters = Proposals::<T>::get(proposal_id)
+					.map_or(0, |proposal| proposal.voter_accounts.len() as u32);
+				weight.saturating_accrue(T::WeightInfo::close_proposal(voters));
 			}
-			Weight::zero()
+
+			// Proposals whose prerequisites were not accepted are cancelled a few at a time.
+			weight.saturating_accrue(Self::cancel_pending());
+			weight
 		}
 	}
 
@@ -157,36 +763,145 @@ pub mod pallet {
 		///
 		/// # Arguments
 		///
-		/// * `origin` - The origin of the transaction, must be a signed account.
+		/// * `origin` - The origin of the transaction, must be allowed to submit on `track`.
+		/// * `track` - The governance track the proposal is created on.
 		/// * `name` - A bounded vector containing the name of the proposal.
 		/// * `description` - A bounded vector containing the description of the proposal.
 		/// * `proposal_duration` - The duration for which the proposal will be open for voting,
 		///   specified in terms of a number of days. This value must be within the range defined
-		///   by `PROPOSAL_DURATION_LIMIT`.
+		///   by `PROPOSAL_DURATION_LIMIT` and the duration range of the track.
+		/// * `anti_sniping` - Optional settings that push the deadline back when a vote close to
+		///   the deadline changes the leading side.
+		/// * `candle_period` - Optional number of blocks before the deadline from which the
+		///   effective close is drawn at random. The result is the tally as of that block.
+		/// * `tie_break` - Optional policy for deciding a tie, overriding `Config::TieBreak`.
+		/// * `co_authors` - Accounts invited to co-author the proposal. The proposal opens once
+		///   all of them have accepted.
+		/// * `prerequisites` - Earlier proposals that must be accepted before the proposal opens.
+		///   The proposal is cancelled if any of them is not accepted.
+		/// * `competes_with` - Optional proposal this one is an alternative to. The proposal joins
+		///   its group and shares its deadline, and at most one proposal of the group is
+		///   accepted. A competing proposal starts voting right away.
 		///
 		/// # Errors
 		///
+		/// * `Error::<T>::BadTrack` - Returned if the track does not exist.
 		/// * `Error::<T>::InvalidProposalDuration` - Returned if the specified proposal duration
-		///   is not within the valid range defined by `PROPOSAL_DURATION_LIMIT`.
+		///   is not within the valid range defined by `PROPOSAL_DURATION_LIMIT` and the track.
+		/// * `Error::<T>::TrackFull` - Returned if the track has its maximum number of open
+		///   proposals.
+		/// * `Error::<T>::NoExpirySlot` - Returned if no block near the deadline is free to close
+		///   the proposal at.
+		/// * `Error::<T>::InvalidAntiSniping` - Returned if the anti-sniping window or extension
+		///   is zero, or if the maximum extension would run the proposal past the maximum duration
+		///   of its track.
+		/// * `Error::<T>::InvalidCandlePeriod` - Returned if the candle period is zero or longer
+		///   than the proposal duration.
+		/// * `Error::<T>::IncompatibleEnding` - Returned if both `anti_sniping` and
+		///   `candle_period` are given.
+		/// * `Error::<T>::InvalidCoAuthors` - Returned if the co-authors include the owner or the
+		///   same account twice.
+		/// * `Error::<T>::InvalidPrerequisite` - Returned if a prerequisite does not exist, has
+		///   already failed or is given twice.
+		/// * `Error::<T>::TooManyDependents` - Returned if a prerequisite has `MaxDependents`
+		///   dependent proposals.
+		/// * `Error::<T>::InvalidGroup` - Returned if the proposal to compete with is not active
+		///   or uses anti-sniping or a candle ending.
+		/// * `Error::<T>::IncompatibleGroup` - Returned if a competing proposal invites co-authors,
+		///   waits on prerequisites or uses anti-sniping or a candle ending.
+		/// * `Error::<T>::GroupFull` - Returned if the group has `MaxGroupSize` proposals.
 		///
 		/// On successfully completion of method CreatedProposal Event will Emit.
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as Config>::WeightInfo::create_proposal())]
 		pub fn create_proposal(
 			origin: OriginFor<T>,
+			track: TrackIdOf<T>,
 			name: BoundedVec<u8, <T as pallet::Config>::NameLimit>,
 			description: BoundedVec<u8, <T as pallet::Config>::DescriptionLimit>,
 			proposal_duration: u32,
+			anti_sniping: Option<AntiSniping<BlockNumberFor<T>>>,
+			candle_period: Option<BlockNumberFor<T>>,
+			tie_break: Option<TieBreak>,
+			co_authors: BoundedVec<T::AccountId, T::MaxCoAuthors>,
+			prerequisites: BoundedVec<T::ProposalId, T::MaxPrerequisites>,
+			competes_with: Option<T::ProposalId>,
 		) -> DispatchResultWithPostInfo {
-			let origin = ensure_signed(origin)?;
+			let track_info = T::Tracks::info(track).ok_or(Error::<T>::BadTrack)?;
+			let origin = T::Tracks::authorize(track, origin)?;
 
 			// The given duration should be in the range from 1 to the given Duration limit.
 			ensure!(
 				(1..=PROPOSAL_DURATION_LIMIT).contains(&proposal_duration),
 				Error::<T>::InvalidProposalDuration
 			);
+			ensure!(
+				(track_info.min_duration..=track_info.max_duration).contains(&proposal_duration),
+				Error::<T>::InvalidProposalDuration
+			);
 
-			Self::do_create_proposal(origin, name, description, proposal_duration)
+			if let Some(settings) = &anti_sniping {
+				ensure!(
+					!settings.window.is_zero() && !settings.extension.is_zero(),
+					Error::<T>::InvalidAntiSniping
+				);
+				let duration: BlockNumberFor<T> = (BLOCKS_PER_DAY * proposal_duration).into();
+				ensure!(
+					duration.saturating_add(settings.max_extension) <= Self::max_duration(track),
+					Error::<T>::InvalidAntiSniping
+				);
+			}
+
+			if let Some(period) = candle_period {
+				ensure!(anti_sniping.is_none(), Error::<T>::IncompatibleEnding);
+				let duration: BlockNumberFor<T> = (BLOCKS_PER_DAY * proposal_duration).into();
+				ensure!(!period.is_zero() && period <= duration, Error::<T>::InvalidCandlePeriod);
+			}
+
+			for (index, co_author) in co_authors.iter().enumerate() {
+				ensure!(
+					*co_author != origin && !co_authors[..index].contains(co_author),
+					Error::<T>::InvalidCoAuthors
+				);
+			}
+
+			// Only prerequisites that have not been accepted yet are waited on.
+			let mut pending = prerequisites.clone();
+			for (index, prerequisite) in prerequisites.iter().enumerate() {
+				ensure!(
+					!prerequisites[..index].contains(prerequisite),
+					Error::<T>::InvalidPrerequisite
+				);
+				let status = Proposals::<T>::get(prerequisite)
+					.ok_or(Error::<T>::InvalidPrerequisite)?
+					.status;
+				ensure!(
+					!matches!(
+						status,
+						ProposalStatus::Rejected
+							| ProposalStatus::Cancelled
+							| ProposalStatus::Superseded
+					),
+					Error::<T>::InvalidPrerequisite
+				);
+				if status == ProposalStatus::Accepted {
+					pending.retain(|id| id != prerequisite);
+				}
+			}
+
+			Self::do_create_proposal(
+				origin,
+				track,
+				name,
+				description,
+				proposal_duration,
+				anti_sniping,
+				candle_period,
+				tie_break,
+				co_authors,
+				pending,
+				competes_with,
+			)
 		}
 
 		/// Cast a vote on an existing proposal.
@@ -201,13 +916,23 @@ pub mod pallet {
 		///
 		/// * `Error::<T>::ProposalDoesNotExist` - Returned if the specified proposal does not exist.
 		/// * `Error::<T>::ProposalNotActive` - Returned if the proposal is not active and cannot be voted on.
-		/// * `Error::<T>::OwnerCannotVote` - Returned if the owner of the proposal attempts to vote on their own proposal.
+		/// * `Error::<T>::OwnerCannotVote` - Returned if the owner or a co-author of the proposal attempts to vote on their own proposal.
+		/// * `Error::<T>::NotInElectorate` - Returned if the account is not in the `Electorate`.
 		/// * `Error::<T>::DuplicateVote` - Returned if the account has already voted on the proposal.
 		/// * `Error::<T>::AccountLimitReached` - Returned if the number of accounts voting on the proposal exceeds the allowed limit.
 		///
-		/// On successfully completion of method CreatedProposal Event will Emit.
+		/// On successfully completion of method CreatedProposal Event will Emit. If the vote
+		/// decides the outcome for the whole `Electorate`, ProposalDecidedEarly Event will Emit.
+		/// If the tally beats the approval and support curves, ProposalPassedEarly Event will
+		/// Emit. If the vote starts or ends a confirmation period, ConfirmStarted or
+		/// ConfirmAborted Event will Emit.
+		///
+		/// The weight charged up front covers closing a proposal with `AccountLimit` voters; a
+		/// vote that does not close the proposal is refunded down to the weight of the vote.
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::vote())]
+		#[pallet::weight(<T as Config>::WeightInfo::vote().saturating_add(
+			<T as Config>::WeightInfo::close_proposal(T::AccountLimit::get())
+		))]
 		pub fn vote(
 			origin: OriginFor<T>,
 			proposal_id: T::ProposalId,
@@ -223,94 +948,2412 @@ pub mod pallet {
 			ensure!(proposal.is_active, Error::<T>::ProposalNotActive);
 
             // Proposal owner cannot vote on the proposal.
-			ensure!(!(proposal.owner == origin), Error::<T>::OwnerCannotVote);
+			ensure!(!proposal.is_author(&origin), Error::<T>::OwnerCannotVote);
+
+			// Only the electorate can vote.
+			ensure!(T::Electorate::contains(&origin), Error::<T>::NotInElectorate);
 
             // User should not vote multiple time on the proposal.
 			ensure!(!(proposal.voter_accounts).contains(&origin), Error::<T>::DuplicateVote);
 
 			// Add this account in voter_accounts list and respective vote option.
-			Proposals::<T>::mutate(proposal_id, |proposal_details| -> DispatchResult {
-				let proposal_info = proposal_details
-					.as_mut()
-					.ok_or(Error::<T>::ProposalDoesNotExist)?;
+			let (auto_extension, closed_early) = Proposals::<T>::try_mutate(
+				proposal_id,
+				|proposal_details| -> Result<_, DispatchError> {
+					let proposal_info = proposal_details
+						.as_mut()
+						.ok_or(Error::<T>::ProposalDoesNotExist)?;
 
-				proposal_info
-					.voter_accounts
-					.try_push(origin.clone())
-					.ok()
-					.ok_or(Error::<T>::AccountLimitReached)?;
-
-				match choice {
-					Vote::YES => {
-						proposal_info
-							.in_support
-							.try_push(origin.clone())
-							.ok()
-							.ok_or(Error::<T>::AccountLimitReached)?;
-						Ok(())
-					},
-					Vote::NO => {
-						proposal_info
-							.in_oppose
-							.try_push(origin.clone())
-							.ok()
-							.ok_or(Error::<T>::AccountLimitReached)?;
-						Ok(())
-					},
-				}
-			})?;
+					let leading = proposal_info.leading();
+					let tally = proposal_info.tally();
 
-			Self::deposit_event(Event::VoteCasted(proposal_id));
-			Ok(().into())
-		}
-	}
-}
+					proposal_info
+						.voter_accounts
+						.try_push(origin.clone())
+						.ok()
+						.ok_or(Error::<T>::AccountLimitReached)?;
 
-impl<T: Config> Pallet<T> {
-	pub fn do_create_proposal(
-		owner: T::AccountId,
-		name: BoundedVec<u8, <T as pallet::Config>::NameLimit>,
-		description: BoundedVec<u8, <T as pallet::Config>::DescriptionLimit>,
-		proposal_duration: u32,
-	) -> DispatchResultWithPostInfo {
-		let bounded_account: BoundedVec<T::AccountId, <T as Config>::AccountLimit> = Vec::new()
-			.clone()
-			.try_into()
-			.map_err(|_| Error::<T>::CannotBeBounded)?;
+					match choice {
+						Vote::YES => {
+							proposal_info
+								.in_support
+								.try_push(origin.clone())
+								.ok()
+								.ok_or(Error::<T>::AccountLimitReached)?;
+						},
+						Vote::NO => {
+							proposal_info
+								.in_oppose
+								.try_push(origin.clone())
+								.ok()
+								.ok_or(Error::<T>::AccountLimitReached)?;
+						},
+					}
 
-		let new_proposal = Proposal {
-			owner: owner.clone(),
-			name,
-			description,
-			is_active: true,
-			voter_accounts: bounded_account.clone(),
-			in_support: bounded_account.clone(),
-			in_oppose: bounded_account.clone(),
-			status: ProposalStatus::VotingInProgress,
-		};
+					Self::checkpoint_candle(proposal_id, proposal_info, tally);
+					Self::checkpoint_tally(proposal_id, proposal_info);
 
-		let proposal_id = NextProposalId::<T>::get().unwrap_or(
-			T::ProposalId::initial_value()
-				.expect("NOT FOUND")
-				.increment()
-				.expect("NOT FOUND"),
-		);
+					let lead = proposal_info.leading();
+					if lead != Leading::Tie {
+						proposal_info.last_lead = lead;
+					}
 
-		// Storing the proposal
-		Proposals::<T>::insert(proposal_id, &new_proposal);
+					// A proposal whose outcome can no longer change is closed right away, unless
+					// it competes in a group.
+					let decided = if proposal_info.status == ProposalStatus::Preparing
+						|| ProposalGroup::<T>::contains_key(proposal_id)
+					{
+						None
+					} else {
+						Self::decided_outcome(proposal_id, proposal_info)
+					};
+					if let Some((status, tied)) = decided {
+						Self::close_early(proposal_id, proposal_info, status.clone(), tied);
+						let event = Event::ProposalDecidedEarly(proposal_id, status, tied);
+						return Ok((None, Some(event)));
+					}
 
-		// Set up the expire time of a particular proposal.
-		let total_block: u32 = BLOCKS_PER_DAY * proposal_duration;
+					// So is a proposal that beats the approval and support curves.
+					if Self::update_passing(proposal_id, proposal_info) {
+						Self::close_early(
+							proposal_id,
+							proposal_info,
+							ProposalStatus::Accepted,
+							false,
+						);
+						return Ok((None, Some(Event::ProposalPassedEarly(proposal_id))));
+					}
 
-		let expire_block = frame_system::Pallet::<T>::block_number() + total_block.into();
-		ProposalExpireTime::<T>::insert(expire_block, proposal_id);
+					// Only a vote that changes the leading side can push the deadline back.
+					if lead == leading {
+						return Ok((None, None));
+					}
+					Ok((Self::apply_anti_sniping(proposal_id, proposal_info), None))
+				},
+			)?;
 
-        // Adding the proposal id for next proposal.
-		let next_proposal_id = proposal_id.increment().expect("NOT FOUND");
-		NextProposalId::<T>::set(Some(next_proposal_id));
+			if let Some((old_deadline, new_deadline)) = auto_extension {
+				Self::deposit_event(Event::ProposalAutoExtended(
+					proposal_id,
+					old_deadline,
+					new_deadline,
+				));
+			}
 
-		Self::deposit_event(Event::CreatedProposal(proposal_id));
+			Self::record_vote(&origin, proposal_id, choice);
+			Self::deposit_event(Event::VoteCasted(proposal_id));
 
-		Ok(().into())
+			let Some(event) = closed_early else {
+				return Ok(Some(T::WeightInfo::vote()).into());
+			};
+			Self::deposit_event(event);
+			let voters = proposal.voter_accounts.len().saturating_add(1) as u32;
+			Ok(Some(T::WeightInfo::vote().saturating_add(T::WeightInfo::close_proposal(voters)))
+				.into())
+		}
+
+		/// Shorten the remaining voting period of an active proposal.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The origin of the transaction, must be `FastTrackOrigin`.
+		/// * `proposal_id` - The identifier of the proposal to fast-track.
+		/// * `voting_period` - The number of blocks, counted from the current block, the proposal
+		///   stays open for. Must be at least `MinFastTrackPeriod`, and at least one block.
+		///
+		/// # Errors
+		///
+		/// * `Error::<T>::FastTrackPeriodTooShort` - Returned if `voting_period` is below
+		///   `MinFastTrackPeriod` or zero.
+		/// * `Error::<T>::ProposalDoesNotExist` - Returned if the specified proposal does not exist.
+		/// * `Error::<T>::ProposalNotActive` - Returned if the proposal is no longer active.
+		/// * `Error::<T>::DeadlineFixedByGroup` - Returned if the proposal competes in a group.
+		/// * `Error::<T>::DeadlineNotShortened` - Returned if the new deadline is not earlier than
+		///   the current one.
+		/// * `Error::<T>::NoExpirySlot` - Returned if no block near the new deadline is free to
+		///   close the proposal at.
+		///
+		/// On successfully completion of method ProposalFastTracked Event will Emit.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::fast_track())]
+		pub fn fast_track(
+			origin: OriginFor<T>,
+			proposal_id: T::ProposalId,
+			voting_period: BlockNumberFor<T>,
+		) -> DispatchResultWithPostInfo {
+			T::FastTrackOrigin::ensure_origin(origin)?;
+
+			// A deadline in the current block would never be reached by `on_initialize`.
+			ensure!(
+				voting_period >= T::MinFastTrackPeriod::get().max(One::one()),
+				Error::<T>::FastTrackPeriodTooShort
+			);
+
+			let proposal =
+				Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+			ensure!(proposal.is_active, Error::<T>::ProposalNotActive);
+			ensure!(
+				!ProposalGroup::<T>::contains_key(proposal_id),
+				Error::<T>::DeadlineFixedByGroup
+			);
+
+			let old_deadline = proposal.deadline;
+			let new_deadline =
+				frame_system::Pallet::<T>::block_number().saturating_add(voting_period);
+			ensure!(new_deadline < old_deadline, Error::<T>::DeadlineNotShortened);
+
+			let new_deadline = Self::reschedule_expiry(proposal_id, old_deadline, new_deadline)?;
+
+			Proposals::<T>::mutate(proposal_id, |proposal_details| {
+				if let Some(proposal_info) = proposal_details {
+					proposal_info.deadline = new_deadline;
+					Self::schedule_curve_check(proposal_id, proposal_info);
+				}
+			});
+
+			Self::deposit_event(Event::ProposalFastTracked(
+				proposal_id,
+				old_deadline,
+				new_deadline,
+			));
+			Ok(().into())
+		}
+
+		/// Push the deadline of an active proposal further out.
+		///
+		/// The proposal authors can extend the deadline once between them, `ExtendOrigin` any
+		/// number of times.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The origin of the transaction, must be `ExtendOrigin` or the signed
+		///   proposal owner or a co-author.
+		/// * `proposal_id` - The identifier of the proposal to extend.
+		/// * `additional` - The number of blocks to add to the current deadline.
+		///
+		/// # Errors
+		///
+		/// * `Error::<T>::InvalidExtension` - Returned if `additional` is zero.
+		/// * `Error::<T>::ProposalDoesNotExist` - Returned if the specified proposal does not exist.
+		/// * `Error::<T>::ProposalNotActive` - Returned if the proposal is no longer active.
+		/// * `Error::<T>::DeadlineFixedByGroup` - Returned if the proposal competes in a group.
+		/// * `Error::<T>::NotProposalOwner` - Returned if a signed origin is not an author of the
+		///   proposal.
+		/// * `Error::<T>::OwnerExtensionUsed` - Returned if an author has already extended the
+		///   deadline.
+		/// * `Error::<T>::ExtensionBeyondLimit` - Returned if the proposal would run for longer
+		///   than the maximum duration of its track.
+		/// * `Error::<T>::TooManyExtensions` - Returned if `MaxExtensions` has been reached.
+		/// * `Error::<T>::NoExpirySlot` - Returned if no block near the new deadline is free to
+		///   close the proposal at.
+		///
+		/// On successfully completion of method ProposalDeadlineExtended Event will Emit.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::extend_deadline())]
+		pub fn extend_deadline(
+			origin: OriginFor<T>,
+			proposal_id: T::ProposalId,
+			additional: BlockNumberFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let maybe_owner = match T::ExtendOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+
+			ensure!(!additional.is_zero(), Error::<T>::InvalidExtension);
+
+			let proposal =
+				Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+			ensure!(proposal.is_active, Error::<T>::ProposalNotActive);
+			ensure!(
+				!ProposalGroup::<T>::contains_key(proposal_id),
+				Error::<T>::DeadlineFixedByGroup
+			);
+
+			let by_owner = maybe_owner.is_some();
+			if let Some(who) = maybe_owner {
+				ensure!(proposal.is_author(&who), Error::<T>::NotProposalOwner);
+				ensure!(
+					!proposal.extensions.iter().any(|ext| ext.by_owner),
+					Error::<T>::OwnerExtensionUsed
+				);
+			}
+
+			let old_deadline = proposal.deadline;
+			let new_deadline = old_deadline.saturating_add(additional);
+
+			let duration_limit = Self::max_duration(proposal.track);
+			ensure!(
+				new_deadline.saturating_sub(proposal.created_at) <= duration_limit,
+				Error::<T>::ExtensionBeyondLimit
+			);
+
+			let mut extensions = proposal.extensions;
+			extensions
+				.try_push(DeadlineExtension {
+					extended_at: frame_system::Pallet::<T>::block_number(),
+					old_deadline,
+					new_deadline,
+					by_owner,
+				})
+				.map_err(|_| Error::<T>::TooManyExtensions)?;
+
+			let new_deadline = Self::reschedule_expiry(proposal_id, old_deadline, new_deadline)?;
+			if let Some(last) = extensions.last_mut() {
+				last.new_deadline = new_deadline;
+			}
+
+			Proposals::<T>::mutate(proposal_id, |proposal_details| {
+				if let Some(proposal_info) = proposal_details {
+					proposal_info.deadline = new_deadline;
+					proposal_info.extensions = extensions;
+					Self::schedule_curve_check(proposal_id, proposal_info);
+				}
+			});
+
+			Self::deposit_event(Event::ProposalDeadlineExtended(
+				proposal_id,
+				old_deadline,
+				new_deadline,
+			));
+			Ok(().into())
+		}
+
+		/// Set the proposal owner's preference, used to decide a tie under
+		/// `TieBreak::OwnerPreference`.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The origin of the transaction, must be the signed proposal owner or a
+		///   co-author.
+		/// * `proposal_id` - The identifier of the proposal.
+		/// * `preference` - The side a tie should go to.
+		///
+		/// # Errors
+		///
+		/// * `Error::<T>::ProposalDoesNotExist` - Returned if the specified proposal does not exist.
+		/// * `Error::<T>::ProposalNotActive` - Returned if the proposal is no longer active.
+		/// * `Error::<T>::NotProposalOwner` - Returned if the caller is not an author of the
+		///   proposal.
+		///
+		/// On successfully completion of method TiePreferenceSet Event will Emit.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_tie_preference())]
+		pub fn set_tie_preference(
+			origin: OriginFor<T>,
+			proposal_id: T::ProposalId,
+			preference: Vote,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			Proposals::<T>::try_mutate(proposal_id, |proposal_details| -> DispatchResult {
+				let proposal_info = proposal_details
+					.as_mut()
+					.ok_or(Error::<T>::ProposalDoesNotExist)?;
+				ensure!(proposal_info.is_active, Error::<T>::ProposalNotActive);
+				ensure!(proposal_info.is_author(&origin), Error::<T>::NotProposalOwner);
+
+				proposal_info.owner_preference = Some(preference.clone());
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::TiePreferenceSet(proposal_id, preference));
+			Ok(().into())
+		}
+
+		/// Place the decision deposit of a preparing proposal, so that it can be decided.
+		///
+		/// The deposit is held from the caller until the proposal closes.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The origin of the transaction, must be a signed account.
+		/// * `proposal_id` - The identifier of the proposal.
+		///
+		/// # Errors
+		///
+		/// * `Error::<T>::ProposalDoesNotExist` - Returned if the specified proposal does not exist.
+		/// * `Error::<T>::ProposalNotActive` - Returned if the proposal is no longer active.
+		/// * `Error::<T>::NotPreparing` - Returned if the proposal is not waiting for a decision
+		///   deposit.
+		/// * `Error::<T>::BadTrack` - Returned if the track of the proposal no longer exists.
+		///
+		/// On successfully completion of method DecisionDepositPlaced Event will Emit. If the
+		/// tally already beats the approval and support curves, ProposalPassedEarly Event will
+		/// Emit.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_decision_deposit())]
+		pub fn place_decision_deposit(
+			origin: OriginFor<T>,
+			proposal_id: T::ProposalId,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			let (amount, passed) = Proposals::<T>::try_mutate(
+				proposal_id,
+				|proposal_details| -> Result<_, DispatchError> {
+					let proposal_info = proposal_details
+						.as_mut()
+						.ok_or(Error::<T>::ProposalDoesNotExist)?;
+					ensure!(proposal_info.is_active, Error::<T>::ProposalNotActive);
+					ensure!(
+						proposal_info.status == ProposalStatus::Preparing,
+						Error::<T>::NotPreparing
+					);
+
+					let track_info =
+						T::Tracks::info(proposal_info.track).ok_or(Error::<T>::BadTrack)?;
+					let amount = track_info.decision_deposit;
+					T::Currency::hold(&HoldReason::DecisionDeposit.into(), &origin, amount)?;
+					DecisionDeposits::<T>::insert(
+						proposal_id,
+						Deposit { who: origin.clone(), amount },
+					);
+
+					Self::set_status(proposal_id, proposal_info, ProposalStatus::VotingInProgress);
+					let passed = Self::update_passing(proposal_id, proposal_info);
+					if passed {
+						Self::close_early(
+							proposal_id,
+							proposal_info,
+							ProposalStatus::Accepted,
+							false,
+						);
+					}
+					Ok((amount, passed))
+				},
+			)?;
+
+			Self::deposit_event(Event::DecisionDepositPlaced(proposal_id, origin, amount));
+			if passed {
+				Self::deposit_event(Event::ProposalPassedEarly(proposal_id));
+			}
+			Ok(().into())
+		}
+
+		/// Second a proposal waiting in the seconding queue.
+		///
+		/// A proposal moves into voting as soon as it has `SecondingThreshold` seconds.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The origin of the transaction, must be a signed account.
+		/// * `proposal_id` - The identifier of the queued proposal.
+		/// * `deposit` - Optional amount to back the second with. It is held until the proposal
+		///   launches or expires from the queue.
+		///
+		/// # Errors
+		///
+		/// * `Error::<T>::ProposalDoesNotExist` - Returned if the specified proposal does not exist.
+		/// * `Error::<T>::NotQueued` - Returned if the proposal is not in the seconding queue.
+		/// * `Error::<T>::OwnerCannotSecond` - Returned if the proposal owner or a co-author
+		///   seconds their own proposal.
+		/// * `Error::<T>::AlreadySeconded` - Returned if the account has already seconded the
+		///   proposal.
+		/// * `Error::<T>::TooManySeconds` - Returned if `MaxSeconds` has been reached.
+		///
+		/// On successfully completion of method Seconded Event will Emit. If the proposal
+		/// reaches the seconding threshold, ProposalLaunched Event will Emit.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::second())]
+		pub fn second(
+			origin: OriginFor<T>,
+			proposal_id: T::ProposalId,
+			deposit: Option<BalanceOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			let proposal =
+				Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+			ensure!(!proposal.is_author(&origin), Error::<T>::OwnerCannotSecond);
+
+			let deposit = deposit.unwrap_or_else(Zero::zero);
+			let threshold_reached = SecondingQueue::<T>::try_mutate(
+				proposal_id,
+				|entry| -> Result<_, DispatchError> {
+					let entry = entry.as_mut().ok_or(Error::<T>::NotQueued)?;
+					ensure!(
+						!entry.seconds.iter().any(|second| second.who == origin),
+						Error::<T>::AlreadySeconded
+					);
+					entry
+						.seconds
+						.try_push(Deposit { who: origin.clone(), amount: deposit })
+						.map_err(|_| Error::<T>::TooManySeconds)?;
+
+					if !deposit.is_zero() {
+						T::Currency::hold(&HoldReason::SecondingDeposit.into(), &origin, deposit)?;
+					}
+					Self::rank_queued(proposal_id, entry)?;
+					Ok(entry.seconds.len() as u32 >= T::SecondingThreshold::get())
+				},
+			)?;
+
+			Self::deposit_event(Event::Seconded(proposal_id, origin, deposit));
+
+			if threshold_reached {
+				Self::launch(proposal_id)?;
+			}
+			Ok(().into())
+		}
+
+		/// Accept the invitation to co-author a proposal.
+		///
+		/// The proposal opens once all invited co-authors have accepted: it enters the seconding
+		/// queue, or starts voting if the queue is disabled.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The origin of the transaction, must be an invited signed account.
+		/// * `proposal_id` - The identifier of the proposal.
+		///
+		/// # Errors
+		///
+		/// * `Error::<T>::NotInvited` - Returned if the caller has no pending invitation to
+		///   co-author the proposal.
+		/// * `Error::<T>::ProposalDoesNotExist` - Returned if the specified proposal does not exist.
+		///
+		/// On successfully completion of method CoAuthorAccepted Event will Emit. If the
+		/// proposal starts voting, ProposalLaunched Event will Emit.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::accept_co_authorship())]
+		pub fn accept_co_authorship(
+			origin: OriginFor<T>,
+			proposal_id: T::ProposalId,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			let mut invitation =
+				CoAuthorInvitations::<T>::get(proposal_id).ok_or(Error::<T>::NotInvited)?;
+			let index = invitation
+				.invited
+				.iter()
+				.position(|invited| *invited == origin)
+				.ok_or(Error::<T>::NotInvited)?;
+			invitation.invited.remove(index);
+
+			Proposals::<T>::try_mutate(proposal_id, |proposal_details| -> DispatchResult {
+				let proposal_info = proposal_details
+					.as_mut()
+					.ok_or(Error::<T>::ProposalDoesNotExist)?;
+				proposal_info
+					.co_authors
+					.try_push(origin.clone())
+					.map_err(|_| Error::<T>::InvalidCoAuthors)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::CoAuthorAccepted(proposal_id, origin));
+
+			if invitation.invited.is_empty() {
+				CoAuthorInvitations::<T>::remove(proposal_id);
+				Self::open_proposal(proposal_id, invitation.duration)?;
+			} else {
+				CoAuthorInvitations::<T>::insert(proposal_id, invitation);
+			}
+			Ok(().into())
+		}
+
+		/// Submit an amendment replacing the description of an active proposal.
+		///
+		/// The amendment is voted on separately for `AmendmentPeriod` blocks. If at least
+		/// `MinAmendmentVotes` accounts vote on it, and more of them for it than against it, the
+		/// proposal moves to a new revision with the amended description.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The origin of the transaction, must be signed by an account in the
+		///   `Electorate`.
+		/// * `proposal_id` - The identifier of the proposal to amend.
+		/// * `description` - A bounded vector containing the replacement description.
+		/// * `reset_votes` - Whether accepting the amendment clears the votes cast on the
+		///   proposal.
+		///
+		/// # Errors
+		///
+		/// * `Error::<T>::ProposalDoesNotExist` - Returned if the specified proposal does not exist.
+		/// * `Error::<T>::NotInElectorate` - Returned if the account is not in the `Electorate`.
+		/// * `Error::<T>::ProposalNotActive` - Returned if the proposal is not active.
+		/// * `Error::<T>::AmendmentPending` - Returned if the proposal already has an amendment
+		///   being voted on.
+		/// * `Error::<T>::TooManyRevisions` - Returned if `MaxRevisions` has been reached.
+		/// * `Error::<T>::NoAmendmentSlot` - Returned if no block near the end of the amendment
+		///   period is free to close the amendment vote at.
+		/// * `Error::<T>::AmendmentTooLate` - Returned if the amendment vote would not close
+		///   before the proposal's deadline.
+		///
+		/// On successfully completion of method AmendmentProposed Event will Emit.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::propose_amendment())]
+		pub fn propose_amendment(
+			origin: OriginFor<T>,
+			proposal_id: T::ProposalId,
+			description: BoundedVec<u8, <T as pallet::Config>::DescriptionLimit>,
+			reset_votes: bool,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			let proposal =
+				Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+			ensure!(T::Electorate::contains(&origin), Error::<T>::NotInElectorate);
+			ensure!(proposal.is_active, Error::<T>::ProposalNotActive);
+			ensure!(!Amendments::<T>::contains_key(proposal_id), Error::<T>::AmendmentPending);
+			ensure!(
+				(Revisions::<T>::decode_len(proposal_id).unwrap_or_default() as u32)
+					< T::MaxRevisions::get(),
+				Error::<T>::TooManyRevisions
+			);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut end = now.saturating_add(T::AmendmentPeriod::get());
+			let mut probes = 0;
+			while AmendmentEnd::<T>::contains_key(end) {
+				probes += 1;
+				ensure!(probes < MAX_EXPIRY_PROBES, Error::<T>::NoAmendmentSlot);
+				end = end.saturating_add(1u32.into());
+			}
+			ensure!(end < proposal.deadline, Error::<T>::AmendmentTooLate);
+
+			AmendmentEnd::<T>::insert(end, proposal_id);
+			Amendments::<T>::insert(
+				proposal_id,
+				Amendment {
+					proposer: origin.clone(),
+					description,
+					reset_votes,
+					in_support: Default::default(),
+					in_oppose: Default::default(),
+					end,
+				},
+			);
+
+			Self::deposit_event(Event::AmendmentProposed(proposal_id, origin, end));
+			Ok(().into())
+		}
+
+		/// Cast a vote on the amendment of a proposal.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The origin of the transaction, must be a signed account.
+		/// * `proposal_id` - The identifier of the amended proposal.
+		/// * `choice` - The vote choice, which can be either `Vote::YES` or `Vote::NO`.
+		///
+		/// # Errors
+		///
+		/// * `Error::<T>::NoAmendment` - Returned if the proposal has no amendment being voted on.
+		/// * `Error::<T>::OwnerCannotVote` - Returned if the caller is the owner or a co-author of
+		///   the proposal, or proposed the amendment.
+		/// * `Error::<T>::NotInElectorate` - Returned if the account is not in the `Electorate`.
+		/// * `Error::<T>::DuplicateVote` - Returned if the account has already voted on the
+		///   amendment.
+		/// * `Error::<T>::AccountLimitReached` - Returned if the number of accounts voting on the
+		///   amendment exceeds the allowed limit.
+		///
+		/// On successfully completion of method AmendmentVoteCast Event will Emit.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::vote_amendment())]
+		pub fn vote_amendment(
+			origin: OriginFor<T>,
+			proposal_id: T::ProposalId,
+			choice: Vote,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			Amendments::<T>::try_mutate(proposal_id, |amendment| -> DispatchResult {
+				let amendment = amendment.as_mut().ok_or(Error::<T>::NoAmendment)?;
+				let proposal =
+					Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+				ensure!(
+					!proposal.is_author(&origin) && amendment.proposer != origin,
+					Error::<T>::OwnerCannotVote
+				);
+				ensure!(T::Electorate::contains(&origin), Error::<T>::NotInElectorate);
+				ensure!(!amendment.has_voted(&origin), Error::<T>::DuplicateVote);
+
+				let votes = match choice {
+					Vote::YES => &mut amendment.in_support,
+					Vote::NO => &mut amendment.in_oppose,
+				};
+				votes
+					.try_push(origin.clone())
+					.map_err(|_| Error::<T>::AccountLimitReached)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::AmendmentVoteCast(proposal_id, origin, choice));
+			Ok(().into())
+		}
+
+		/// Replace the name and description of a proposal nobody has voted on yet.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The origin of the transaction, must be the signed proposal owner or a
+		///   co-author.
+		/// * `proposal_id` - The identifier of the proposal to edit.
+		/// * `name` - A bounded vector containing the new name of the proposal.
+		/// * `description` - A bounded vector containing the new description of the proposal.
+		///
+		/// # Errors
+		///
+		/// * `Error::<T>::ProposalDoesNotExist` - Returned if the specified proposal does not exist.
+		/// * `Error::<T>::NotProposalOwner` - Returned if the caller is not an author of the
+		///   proposal.
+		/// * `Error::<T>::ProposalNotActive` - Returned if the proposal has closed.
+		/// * `Error::<T>::ProposalHasVotes` - Returned if votes have been cast on the proposal.
+		/// * `Error::<T>::AmendmentPending` - Returned if the proposal has an amendment being
+		///   voted on.
+		/// * `Error::<T>::TooManyRevisions` - Returned if `MaxRevisions` has been reached.
+		///
+		/// On successfully completion of method ProposalEdited Event will Emit.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::edit_proposal())]
+		pub fn edit_proposal(
+			origin: OriginFor<T>,
+			proposal_id: T::ProposalId,
+			name: BoundedVec<u8, <T as pallet::Config>::NameLimit>,
+			description: BoundedVec<u8, <T as pallet::Config>::DescriptionLimit>,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+
+			let content_hash = T::Hashing::hash_of(&(&name, &description));
+			let revision = Proposals::<T>::try_mutate(
+				proposal_id,
+				|proposal_details| -> Result<_, DispatchError> {
+					let proposal_info = proposal_details
+						.as_mut()
+						.ok_or(Error::<T>::ProposalDoesNotExist)?;
+					ensure!(proposal_info.is_author(&origin), Error::<T>::NotProposalOwner);
+					ensure!(
+						!matches!(
+							proposal_info.status,
+							ProposalStatus::Accepted
+								| ProposalStatus::Rejected
+								| ProposalStatus::Cancelled
+								| ProposalStatus::Superseded
+						),
+						Error::<T>::ProposalNotActive
+					);
+					ensure!(proposal_info.voter_accounts.is_empty(), Error::<T>::ProposalHasVotes);
+					ensure!(
+						!Amendments::<T>::contains_key(proposal_id),
+						Error::<T>::AmendmentPending
+					);
+
+					Self::record_revision(
+						proposal_id,
+						proposal_info,
+						origin,
+						name,
+						description,
+						content_hash,
+					)
+				},
+			)?;
+
+			Self::deposit_event(Event::ProposalEdited(proposal_id, revision, content_hash));
+			Ok(().into())
+		}
+
+		/// Archive a closed proposal, removing its tally checkpoints. The proposal itself and its
+		/// voter root are kept.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The origin of the transaction, must be signed.
+		/// * `proposal_id` - The identifier of the proposal to archive.
+		///
+		/// # Errors
+		///
+		/// * `Error::<T>::ProposalDoesNotExist` - Returned if the specified proposal does not exist.
+		/// * `Error::<T>::ProposalNotClosed` - Returned if the proposal has not closed.
+		/// * `Error::<T>::ArchivePeriodNotOver` - Returned if `ArchivePeriod` blocks have not
+		///   passed since the deadline of the proposal.
+		/// * `Error::<T>::NothingToArchive` - Returned if the proposal has no tally checkpoints.
+		///
+		/// On successfully completion of method ProposalArchived Event will Emit.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::archive_proposal())]
+		pub fn archive_proposal(
+			origin: OriginFor<T>,
+			proposal_id: T::ProposalId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let proposal =
+				Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+			ensure!(
+				matches!(
+					proposal.status,
+					ProposalStatus::Accepted
+						| ProposalStatus::Rejected
+						| ProposalStatus::Cancelled
+						| ProposalStatus::Superseded
+				),
+				Error::<T>::ProposalNotClosed
+			);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				now >= proposal.deadline.saturating_add(T::ArchivePeriod::get()),
+				Error::<T>::ArchivePeriodNotOver
+			);
+
+			let checkpoints = TallyCheckpoints::<T>::take(proposal_id);
+			ensure!(!checkpoints.is_empty(), Error::<T>::NothingToArchive);
+
+			Self::deposit_event(Event::ProposalArchived(proposal_id));
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn do_create_proposal(
+		owner: T::AccountId,
+		track: TrackIdOf<T>,
+		name: BoundedVec<u8, <T as pallet::Config>::NameLimit>,
+		description: BoundedVec<u8, <T as pallet::Config>::DescriptionLimit>,
+		proposal_duration: u32,
+		anti_sniping: Option<AntiSniping<BlockNumberFor<T>>>,
+		candle_period: Option<BlockNumberFor<T>>,
+		tie_break: Option<TieBreak>,
+		co_authors: BoundedVec<T::AccountId, T::MaxCoAuthors>,
+		prerequisites: BoundedVec<T::ProposalId, T::MaxPrerequisites>,
+		competes_with: Option<T::ProposalId>,
+	) -> DispatchResultWithPostInfo {
+		let track_info = T::Tracks::info(track).ok_or(Error::<T>::BadTrack)?;
+
+		// The track should have room for another open proposal.
+		let active = ActiveProposalCount::<T>::get(track);
+		ensure!(active < track_info.max_active, Error::<T>::TrackFull);
+
+		let bounded_account: BoundedVec<T::AccountId, <T as Config>::AccountLimit> = Vec::new()
+			.clone()
+			.try_into()
+			.map_err(|_| Error::<T>::CannotBeBounded)?;
+
+		let proposal_id = NextProposalId::<T>::get().unwrap_or(
+			T::ProposalId::initial_value()
+				.expect("NOT FOUND")
+				.increment()
+				.expect("NOT FOUND"),
+		);
+
+		// Set up the expire time of a particular proposal. A proposal waiting on its co-authors
+		// or in the queue expires unless it opens or is launched first. One waiting on its
+		// prerequisites is settled when they close instead, and a competing one closes with its
+		// group.
+		let invited = !co_authors.is_empty();
+		let dependent = !prerequisites.is_empty();
+		let competing = competes_with.is_some();
+		let queued =
+			!invited && !dependent && !competing && !T::SecondingThreshold::get().is_zero();
+		let total_block: u32 = BLOCKS_PER_DAY * proposal_duration;
+
+		let group = match competes_with {
+			Some(rival_id) => {
+				ensure!(
+					!invited && !dependent && anti_sniping.is_none() && candle_period.is_none(),
+					Error::<T>::IncompatibleGroup
+				);
+				Some(Self::join_group(rival_id, proposal_id)?)
+			},
+			None => None,
+		};
+
+		let created_at = frame_system::Pallet::<T>::block_number();
+		let deadline = if let Some((_, group_deadline)) = group {
+			group_deadline
+		} else if invited {
+			Self::schedule_expiry(
+				proposal_id,
+				created_at.saturating_add(T::InvitationPeriod::get()),
+			)?
+		} else if dependent {
+			created_at
+		} else if queued {
+			Self::schedule_expiry(proposal_id, created_at.saturating_add(T::QueuePeriod::get()))?
+		} else {
+			Self::schedule_expiry(proposal_id, created_at + total_block.into())?
+		};
+
+		let status = if invited {
+			ProposalStatus::AwaitingCoAuthors
+		} else if dependent {
+			ProposalStatus::AwaitingPrerequisites
+		} else if queued {
+			ProposalStatus::Queued
+		} else {
+			Self::launch_status(track_info)
+		};
+
+		let new_proposal = Proposal {
+			owner: owner.clone(),
+			co_authors: Default::default(),
+			name,
+			description,
+			is_active: !invited && !dependent && !queued,
+			voter_accounts: bounded_account.clone(),
+			in_support: bounded_account.clone(),
+			in_oppose: bounded_account.clone(),
+			status,
+			deadline,
+			created_at,
+			extensions: Default::default(),
+			anti_sniping,
+			auto_extended: Zero::zero(),
+			candle_period,
+			tie_break,
+			owner_preference: None,
+			last_lead: Leading::Tie,
+			track,
+			confirm_end: None,
+			curve_check: None,
+			revision: 0,
+		};
+
+		// Hold the submission deposit until the proposal closes.
+		if !track_info.submission_deposit.is_zero() {
+			T::Currency::hold(
+				&HoldReason::SubmissionDeposit.into(),
+				&owner,
+				track_info.submission_deposit,
+			)?;
+			SubmissionDeposits::<T>::insert(
+				proposal_id,
+				Deposit { who: owner.clone(), amount: track_info.submission_deposit },
+			);
+		}
+		ActiveProposalCount::<T>::insert(track, active.saturating_add(1));
+
+		if invited {
+			CoAuthorInvitations::<T>::insert(
+				proposal_id,
+				Invitation { duration: proposal_duration, invited: co_authors },
+			);
+		}
+		if dependent {
+			for prerequisite in prerequisites.iter() {
+				Dependents::<T>::try_mutate(prerequisite, |dependents| {
+					dependents.try_push(proposal_id)
+				})
+				.map_err(|_| Error::<T>::TooManyDependents)?;
+			}
+			Prerequisites::<T>::insert(
+				proposal_id,
+				Dependency { duration: proposal_duration, pending: prerequisites },
+			);
+		}
+		if queued {
+			let entry = QueueEntry {
+				duration: proposal_duration,
+				queued_at: created_at,
+				seconds: Default::default(),
+			};
+			Self::rank_queued(proposal_id, &entry)?;
+			SecondingQueue::<T>::insert(proposal_id, entry);
+		}
+
+		if let Some((group_id, deadline)) = group {
+			Self::deposit_event(Event::JoinedGroup(proposal_id, group_id, deadline));
+		}
+
+		// Storing the proposal
+		Proposals::<T>::insert(proposal_id, &new_proposal);
+		ProposalsByOwner::<T>::insert(&owner, proposal_id, ());
+		ProposalsByStatus::<T>::insert(&new_proposal.status, proposal_id, ());
+		ProposalsByCreation::<T>::insert(Self::creation_key(created_at), proposal_id, ());
+		ProposalStatistics::<T>::mutate(|stats| {
+			stats.total_proposals = stats.total_proposals.saturating_add(1);
+		});
+
+        // Adding the proposal id for next proposal.
+		let next_proposal_id = proposal_id.increment().expect("NOT FOUND");
+		NextProposalId::<T>::set(Some(next_proposal_id));
+
+		Self::deposit_event(Event::CreatedProposal(proposal_id));
+
+		Ok(().into())
+	}
+
+	/// Schedule `proposal_id` to close at `block`, or at the first later block that has no
+	/// proposal expiring on it. Returns the block the proposal was scheduled at.
+	pub(crate) fn schedule_expiry(
+		proposal_id: T::ProposalId,
+		block: BlockNumberFor<T>,
+	) -> Result<BlockNumberFor<T>, DispatchError> {
+		let expire_block = Self::free_expiry_slot(proposal_id, block)?;
+		ProposalExpireTime::<T>::insert(expire_block, proposal_id);
+		Ok(expire_block)
+	}
+
+	/// First block from `block` on, out of `MAX_EXPIRY_PROBES`, with no other proposal than
+	/// `proposal_id` expiring on it.
+	pub(crate) fn free_expiry_slot(
+		proposal_id: T::ProposalId,
+		block: BlockNumberFor<T>,
+	) -> Result<BlockNumberFor<T>, DispatchError> {
+		let mut expire_block = block;
+		for _ in 0..MAX_EXPIRY_PROBES {
+			match ProposalExpireTime::<T>::get(expire_block) {
+				Some(scheduled) if scheduled != proposal_id => {
+					expire_block = expire_block.saturating_add(One::one());
+				},
+				_ => return Ok(expire_block),
+			}
+		}
+		Err(Error::<T>::NoExpirySlot.into())
+	}
+
+	/// Add the vote of `who` to its most recent votes, dropping its oldest vote if the index is
+	/// full.
+	pub(crate) fn record_vote(who: &T::AccountId, proposal_id: T::ProposalId, choice: Vote) {
+		let first_vote = !VotesByAccount::<T>::contains_key(who);
+		ProposalStatistics::<T>::mutate(|stats| {
+			stats.total_votes = stats.total_votes.saturating_add(1);
+			if first_vote {
+				stats.unique_voters = stats.unique_voters.saturating_add(1);
+			}
+		});
+		VotesByAccount::<T>::mutate(who, |votes| {
+			if votes.is_full() && !votes.is_empty() {
+				votes.remove(0);
+			}
+			let _ = votes.try_push((proposal_id, choice));
+		});
+	}
+
+	/// Remove the votes on `proposal_id` from the most recent votes of each of `voters`.
+	pub(crate) fn forget_votes(proposal_id: T::ProposalId, voters: &[T::AccountId]) {
+		for who in voters {
+			VotesByAccount::<T>::mutate(who, |votes| {
+				votes.retain(|(voted_on, _)| *voted_on != proposal_id)
+			});
+		}
+	}
+
+	/// Set the status of `proposal`, keeping the status index up to date.
+	pub(crate) fn set_status(
+		proposal_id: T::ProposalId,
+		proposal: &mut ProposalOf<T>,
+		status: ProposalStatus,
+	) {
+		ProposalsByStatus::<T>::remove(&proposal.status, proposal_id);
+		ProposalsByStatus::<T>::insert(&status, proposal_id, ());
+		let previous = sp_std::mem::replace(&mut proposal.status, status);
+		Self::record_close(proposal, &previous);
+	}
+
+	/// Count `proposal` in the statistics if its status is final. Its votes and turnout are
+	/// only counted if it was open for voting, in status `previous`, and its turnout only if the
+	/// electorate has a fixed size.
+	pub(crate) fn record_close(proposal: &ProposalOf<T>, previous: &ProposalStatus) {
+		let was_open = matches!(
+			previous,
+			ProposalStatus::VotingInProgress
+				| ProposalStatus::Preparing
+				| ProposalStatus::Confirming
+		);
+		let (support, oppose) = proposal.tally();
+		let votes = support.saturating_add(oppose);
+		let turnout = T::Electorate::size()
+			.map(|_| Perbill::from_rational(votes, Self::eligible_voters(proposal).max(1)));
+		ProposalStatistics::<T>::mutate(|stats| {
+			let count = match proposal.status {
+				ProposalStatus::Accepted => &mut stats.accepted,
+				ProposalStatus::Rejected => &mut stats.rejected,
+				ProposalStatus::Superseded => &mut stats.superseded,
+				ProposalStatus::Cancelled => {
+					stats.cancelled = stats.cancelled.saturating_add(1);
+					return;
+				},
+				_ => return,
+			};
+			*count = count.saturating_add(1);
+			if !was_open {
+				return;
+			}
+			stats.voted = stats.voted.saturating_add(1);
+			stats.voted_votes = stats.voted_votes.saturating_add(votes.into());
+			if let Some(turnout) = turnout {
+				stats.turnout_samples = stats.turnout_samples.saturating_add(1);
+				stats.turnout_sum = stats
+					.turnout_sum
+					.saturating_add(turnout.deconstruct().into());
+			}
+		});
+	}
+
+	/// Key of block `block` in the creation index.
+	pub(crate) fn creation_key(block: BlockNumberFor<T>) -> [u8; 8] {
+		block.saturated_into::<u64>().to_be_bytes()
+	}
+
+	/// Status a proposal on a track with `track_info` starts voting with: it waits for a
+	/// decision deposit if the track asks for one.
+	pub(crate) fn launch_status(track_info: &TrackInfo<BalanceOf<T>>) -> ProposalStatus {
+		if track_info.decision_deposit.is_zero() {
+			ProposalStatus::VotingInProgress
+		} else {
+			ProposalStatus::Preparing
+		}
+	}
+
+	/// Move a queued proposal into voting for the duration it was created with, returning the
+	/// deposits of its seconds.
+	pub(crate) fn launch(proposal_id: T::ProposalId) -> DispatchResult {
+		let Some(entry) = SecondingQueue::<T>::get(proposal_id) else { return Ok(()) };
+		Self::start_voting(proposal_id, entry.duration)?;
+		SecondingQueue::<T>::remove(proposal_id);
+		Self::unrank_queued(proposal_id);
+		Self::release_seconds(&entry);
+		Ok(())
+	}
+
+	/// Open a proposal whose co-authors and prerequisites have all accepted or been accepted:
+	/// it enters the seconding queue, or starts voting if the queue is disabled. A proposal with
+	/// prerequisites left waits on them instead.
+	pub(crate) fn open_proposal(proposal_id: T::ProposalId, duration: u32) -> DispatchResult {
+		if Prerequisites::<T>::contains_key(proposal_id) {
+			Proposals::<T>::mutate(proposal_id, |proposal_details| {
+				if let Some(proposal_info) = proposal_details {
+					Self::unschedule_expiry(proposal_id, proposal_info.deadline);
+					Self::set_status(
+						proposal_id,
+						proposal_info,
+						ProposalStatus::AwaitingPrerequisites,
+					);
+				}
+			});
+			return Ok(());
+		}
+
+		if T::SecondingThreshold::get().is_zero() {
+			return Self::start_voting(proposal_id, duration);
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(
+			(QueueRanking::<T>::decode_len().unwrap_or_default() as u32) < T::MaxQueued::get(),
+			Error::<T>::QueueFull
+		);
+		Proposals::<T>::try_mutate(proposal_id, |proposal_details| -> DispatchResult {
+			let proposal_info = proposal_details
+				.as_mut()
+				.ok_or(Error::<T>::ProposalDoesNotExist)?;
+			proposal_info.deadline = Self::reschedule_expiry(
+				proposal_id,
+				proposal_info.deadline,
+				now.saturating_add(T::QueuePeriod::get()),
+			)?;
+			Self::set_status(proposal_id, proposal_info, ProposalStatus::Queued);
+			Ok(())
+		})?;
+		let entry = QueueEntry { duration, queued_at: now, seconds: Default::default() };
+		Self::rank_queued(proposal_id, &entry)?;
+		SecondingQueue::<T>::insert(proposal_id, entry);
+		Ok(())
+	}
+
+	/// Start the voting period of a proposal, lasting `duration` days from now.
+	pub(crate) fn start_voting(proposal_id: T::ProposalId, duration: u32) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+		let voting_period: BlockNumberFor<T> = (BLOCKS_PER_DAY * duration).into();
+		let deadline = Proposals::<T>::try_mutate(proposal_id, |proposal_details| {
+			let proposal_info = proposal_details
+				.as_mut()
+				.ok_or(Error::<T>::ProposalDoesNotExist)?;
+			let track_info = T::Tracks::info(proposal_info.track).ok_or(Error::<T>::BadTrack)?;
+			proposal_info.deadline = Self::reschedule_expiry(
+				proposal_id,
+				proposal_info.deadline,
+				now.saturating_add(voting_period),
+			)?;
+
+			Self::set_status(proposal_id, proposal_info, Self::launch_status(track_info));
+			proposal_info.is_active = true;
+			ProposalsByCreation::<T>::remove(
+				Self::creation_key(proposal_info.created_at),
+				proposal_id,
+			);
+			ProposalsByCreation::<T>::insert(Self::creation_key(now), proposal_id, ());
+			proposal_info.created_at = now;
+			Ok::<_, DispatchError>(proposal_info.deadline)
+		})?;
+
+		Self::deposit_event(Event::ProposalLaunched(proposal_id, deadline));
+		Ok(())
+	}
+
+	/// Launch the queued proposal with the most seconds, if any was seconded. Ties go to the
+	/// proposal backed with the larger deposit, then to the one queued first. Returns the weight
+	/// consumed.
+	pub(crate) fn launch_most_seconded() -> Weight {
+		let ranking = QueueRanking::<T>::get();
+		let most_seconded = ranking
+			.first()
+			.filter(|(_, seconds, _, _)| *seconds > 0)
+			.map(|(proposal_id, _, _, _)| *proposal_id);
+
+		// A proposal that cannot be scheduled stays in the queue.
+		if let Some(proposal_id) = most_seconded {
+			let _ = Self::launch(proposal_id);
+		}
+		T::WeightInfo::launch_most_seconded(ranking.len() as u32)
+	}
+
+	/// Move a queued proposal to its place in `QueueRanking` for the seconds of `entry`.
+	pub(crate) fn rank_queued(
+		proposal_id: T::ProposalId,
+		entry: &QueueEntryOf<T>,
+	) -> DispatchResult {
+		let rank = (entry.seconds.len() as u32, entry.backing(), Reverse(entry.queued_at));
+		QueueRanking::<T>::try_mutate(|ranking| {
+			ranking.retain(|(queued, _, _, _)| *queued != proposal_id);
+			let position = ranking.partition_point(|(_, seconds, backing, queued_at)| {
+				(*seconds, *backing, Reverse(*queued_at)) >= rank
+			});
+			ranking
+				.try_insert(position, (proposal_id, rank.0, rank.1, entry.queued_at))
+				.map_err(|_| Error::<T>::QueueFull.into())
+		})
+	}
+
+	/// Remove a proposal leaving the seconding queue from `QueueRanking`.
+	pub(crate) fn unrank_queued(proposal_id: T::ProposalId) {
+		QueueRanking::<T>::mutate(|ranking| {
+			ranking.retain(|(queued, _, _, _)| *queued != proposal_id)
+		});
+	}
+
+	/// Release the deposits backing the seconds of a queued proposal.
+	pub(crate) fn release_seconds(entry: &QueueEntryOf<T>) {
+		for second in entry
+			.seconds
+			.iter()
+			.filter(|second| !second.amount.is_zero())
+		{
+			let _ = T::Currency::release(
+				&HoldReason::SecondingDeposit.into(),
+				&second.who,
+				second.amount,
+				Precision::BestEffort,
+			);
+		}
+	}
+
+	/// Result of a proposal closed with the given tally at block `at`, and whether the tally
+	/// was tied.
+	///
+	/// A proposal passes if the turnout reaches the minimum turnout at `at` and the share of
+	/// votes in support is above the approval threshold at `at`. A share exactly on the
+	/// threshold is a tie.
+	pub(crate) fn outcome(
+		proposal_id: T::ProposalId,
+		proposal: &ProposalOf<T>,
+		support: u32,
+		oppose: u32,
+		at: BlockNumberFor<T>,
+	) -> (ProposalStatus, bool) {
+		let Some((min_turnout, threshold)) = Self::thresholds(proposal, at) else {
+			return (ProposalStatus::Rejected, false);
+		};
+
+		let turnout = support.saturating_add(oppose);
+		if Perbill::from_rational(turnout, Self::eligible_voters(proposal).max(1)) < min_turnout {
+			return (ProposalStatus::Rejected, false);
+		}
+
+		let approval =
+			if turnout.is_zero() { threshold } else { Perbill::from_rational(support, turnout) };
+
+		if approval > threshold {
+			(ProposalStatus::Accepted, false)
+		} else if approval < threshold {
+			(ProposalStatus::Rejected, false)
+		} else {
+			(Self::break_tie(proposal_id, proposal), true)
+		}
+	}
+
+	/// Minimum turnout and approval threshold of `proposal` at block `at`: the value of the
+	/// curves at that point of its lifetime, or else the fixed values of its track.
+	pub(crate) fn thresholds(
+		proposal: &ProposalOf<T>,
+		at: BlockNumberFor<T>,
+	) -> Option<(Perbill, Perbill)> {
+		let track = T::Tracks::info(proposal.track)?;
+
+		let lifetime = proposal.deadline.saturating_sub(proposal.created_at);
+		let elapsed = at.saturating_sub(proposal.created_at).min(lifetime);
+		let progress = if lifetime.is_zero() {
+			Perbill::one()
+		} else {
+			Perbill::from_rational(
+				elapsed.saturated_into::<u32>(),
+				lifetime.saturated_into::<u32>(),
+			)
+		};
+
+		let min_turnout =
+			T::SupportCurve::get().map_or(track.min_turnout, |curve| curve.threshold(progress));
+		let threshold = T::ApprovalCurve::get()
+			.map_or(track.approval_threshold, |curve| curve.threshold(progress));
+		Some((min_turnout, threshold))
+	}
+
+	/// Whether proposals pass as soon as their tally beats the approval and support curves.
+	pub(crate) fn uses_curves() -> bool {
+		T::ApprovalCurve::get().is_some() || T::SupportCurve::get().is_some()
+	}
+
+	/// Decide a tied proposal according to its `TieBreak` policy.
+	pub(crate) fn break_tie(
+		proposal_id: T::ProposalId,
+		proposal: &ProposalOf<T>,
+	) -> ProposalStatus {
+		let accepted = match proposal.tie_break.unwrap_or_else(T::TieBreak::get) {
+			TieBreak::Reject => false,
+			TieBreak::Accept => true,
+			TieBreak::StatusQuo => proposal.last_lead == Leading::Support,
+			TieBreak::OwnerPreference => proposal.owner_preference == Some(Vote::YES),
+			TieBreak::Random => {
+				let (random, _) = T::Randomness::random(&(b"tie", proposal_id).encode());
+				let seed =
+					u32::decode(&mut TrailingZeroInput::new(random.as_ref())).unwrap_or_default();
+				seed % 2 == 1
+			},
+		};
+
+		if accepted {
+			ProposalStatus::Accepted
+		} else {
+			ProposalStatus::Rejected
+		}
+	}
+
+	/// The final result of `proposal` if the remaining `Electorate` can no longer change it.
+	pub(crate) fn decided_outcome(
+		proposal_id: T::ProposalId,
+		proposal: &ProposalOf<T>,
+	) -> Option<(ProposalStatus, bool)> {
+		T::Electorate::size()?;
+
+		// A candle ending may settle on an earlier tally than the current one.
+		if proposal.candle_period.is_some() {
+			return None;
+		}
+
+		let (support, oppose) = proposal.tally();
+		let remaining =
+			Self::eligible_voters(proposal).saturating_sub(support.saturating_add(oppose));
+
+		// Thresholds only fall over time, so the result at the deadline is the final one.
+		let deadline = proposal.deadline;
+		let current = Self::outcome(proposal_id, proposal, support, oppose, deadline);
+		if remaining.is_zero() {
+			return Some(current);
+		}
+
+		// Turnout only grows and approval moves monotonically with the remaining votes, so
+		// without ties at the extremes every possible result ends the same way.
+		let if_all_support = Self::outcome(
+			proposal_id,
+			proposal,
+			support.saturating_add(remaining),
+			oppose,
+			deadline,
+		);
+		let if_all_oppose = Self::outcome(
+			proposal_id,
+			proposal,
+			support,
+			oppose.saturating_add(remaining),
+			deadline,
+		);
+		let decided = current == if_all_support && current == if_all_oppose && !current.1;
+		decided.then_some(current)
+	}
+
+	/// Number of accounts that can be expected to vote on `proposal`, excluding its owner and
+	/// co-authors: the `Electorate` if it is fixed, capped by `AccountLimit`.
+	pub(crate) fn eligible_voters(proposal: &ProposalOf<T>) -> u32 {
+		let authors = (proposal.co_authors.len() as u32).saturating_add(1);
+		let electorate =
+			T::Electorate::size().map_or(u32::MAX, |size| size.saturating_sub(authors));
+		electorate.min(T::AccountLimit::get())
+	}
+
+	/// Longest a proposal on `track` can run for, in blocks.
+	pub(crate) fn max_duration(track: TrackIdOf<T>) -> BlockNumberFor<T> {
+		let days = T::Tracks::info(track)
+			.map_or(PROPOSAL_DURATION_LIMIT, |info| info.max_duration)
+			.min(PROPOSAL_DURATION_LIMIT);
+		(BLOCKS_PER_DAY * days).into()
+	}
+
+	/// Release what a proposal holds once it has closed: its deposits, its pending
+	/// confirmation and its place on the track.
+	pub(crate) fn finish_proposal(proposal_id: T::ProposalId, proposal: &ProposalOf<T>) {
+		if let Some(deposit) = SubmissionDeposits::<T>::take(proposal_id) {
+			let _ = T::Currency::release(
+				&HoldReason::SubmissionDeposit.into(),
+				&deposit.who,
+				deposit.amount,
+				Precision::BestEffort,
+			);
+		}
+		if let Some(deposit) = DecisionDeposits::<T>::take(proposal_id) {
+			let _ = T::Currency::release(
+				&HoldReason::DecisionDeposit.into(),
+				&deposit.who,
+				deposit.amount,
+				Precision::BestEffort,
+			);
+		}
+		if let Some(end) = proposal.confirm_end {
+			ConfirmationEnd::<T>::remove(end);
+		}
+		if let Some(block) = proposal.curve_check {
+			CurveCheck::<T>::remove(block);
+		}
+		if let Some(amendment) = Amendments::<T>::take(proposal_id) {
+			AmendmentEnd::<T>::remove(amendment.end);
+		}
+		ActiveProposalCount::<T>::mutate(proposal.track, |active| {
+			*active = active.saturating_sub(1)
+		});
+
+		Prerequisites::<T>::remove(proposal_id);
+		Self::resolve_dependents(proposal_id, &proposal.status);
+	}
+
+	/// Settle the proposals waiting on `proposal_id` now that it closed with `status`: they
+	/// are queued for cancellation unless it was accepted, and open once all their prerequisites
+	/// were.
+	pub(crate) fn resolve_dependents(proposal_id: T::ProposalId, status: &ProposalStatus) {
+		for dependent in Dependents::<T>::take(proposal_id) {
+			let Some(mut dependency) = Prerequisites::<T>::get(dependent) else { continue };
+
+			if *status != ProposalStatus::Accepted {
+				PendingCancellations::<T>::insert(dependent, proposal_id);
+				continue;
+			}
+
+			dependency
+				.pending
+				.retain(|prerequisite| *prerequisite != proposal_id);
+			if !dependency.pending.is_empty() {
+				Prerequisites::<T>::insert(dependent, dependency);
+				continue;
+			}
+
+			// A proposal still waiting on its co-authors opens once they have all accepted.
+			Prerequisites::<T>::remove(dependent);
+			let waiting = Proposals::<T>::get(dependent)
+				.map_or(false, |proposal| proposal.status == ProposalStatus::AwaitingPrerequisites);
+			// One that cannot be scheduled is cancelled rather than left waiting.
+			if waiting && Self::open_proposal(dependent, dependency.duration).is_err() {
+				PendingCancellations::<T>::insert(dependent, proposal_id);
+			}
+		}
+	}
+
+	/// Cancel up to `MaxCancellations` of the proposals pending cancellation, including those
+	/// queued by the cancellations themselves. Returns the weight consumed.
+	pub(crate) fn cancel_pending() -> Weight {
+		let mut cancelled = 0u32;
+		while cancelled < T::MaxCancellations::get() {
+			let Some((proposal_id, prerequisite)) = PendingCancellations::<T>::iter().next() else {
+				break;
+			};
+			PendingCancellations::<T>::remove(proposal_id);
+			Self::cancel_dependent(proposal_id, prerequisite);
+			cancelled.saturating_inc();
+		}
+		T::WeightInfo::cancel_dependent()
+			.saturating_mul(cancelled.into())
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+
+	/// Cancel a proposal waiting on `prerequisite`, which was not accepted. Proposals depending
+	/// on it are queued for cancellation in turn.
+	pub(crate) fn cancel_dependent(proposal_id: T::ProposalId, prerequisite: T::ProposalId) {
+		let Some(mut proposal) = Proposals::<T>::get(proposal_id) else { return };
+		// It may have expired while pending.
+		if !matches!(
+			proposal.status,
+			ProposalStatus::AwaitingCoAuthors | ProposalStatus::AwaitingPrerequisites
+		) {
+			return;
+		}
+
+		if proposal.status == ProposalStatus::AwaitingCoAuthors {
+			CoAuthorInvitations::<T>::remove(proposal_id);
+			Self::unschedule_expiry(proposal_id, proposal.deadline);
+		}
+		Self::set_status(proposal_id, &mut proposal, ProposalStatus::Cancelled);
+		proposal.is_active = false;
+		Proposals::<T>::insert(proposal_id, &proposal);
+		let root = Self::commit_voters(proposal_id, &proposal);
+
+		Self::deposit_event(Event::DependencyFailed(proposal_id, prerequisite));
+		Self::deposit_event(Event::ProposalClosed(
+			proposal_id,
+			ProposalStatus::Cancelled,
+			false,
+			root,
+		));
+
+		Self::finish_proposal(proposal_id, &proposal);
+	}
+
+	/// Whether `proposal` would be accepted on its current tally at block `at`, without a tie.
+	pub(crate) fn is_passing(
+		proposal_id: T::ProposalId,
+		proposal: &ProposalOf<T>,
+		at: BlockNumberFor<T>,
+	) -> bool {
+		let (support, oppose) = proposal.tally();
+		let outcome = Self::outcome(proposal_id, proposal, support, oppose, at);
+		outcome == (ProposalStatus::Accepted, false)
+	}
+
+	/// Start or abort the confirmation period of a deciding proposal as it starts or stops
+	/// passing. A passing proposal keeps deciding if none of the `MAX_EXPIRY_PROBES` blocks
+	/// from the end of its confirmation period is free, and is looked at again on its next vote
+	/// or curve check.
+	pub(crate) fn update_confirmation(proposal_id: T::ProposalId, proposal: &mut ProposalOf<T>) {
+		let Some(track) = T::Tracks::info(proposal.track) else { return };
+
+		// A candle ending may settle on an earlier tally than the current one.
+		if track.confirm_period.is_zero() || proposal.candle_period.is_some() {
+			return;
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let passing = Self::is_passing(proposal_id, proposal, now);
+		match proposal.status {
+			ProposalStatus::VotingInProgress if passing => {
+				let mut end = now.saturating_add(track.confirm_period.into());
+				let mut probes = 0;
+				while ConfirmationEnd::<T>::contains_key(end) {
+					probes += 1;
+					if probes >= MAX_EXPIRY_PROBES {
+						return;
+					}
+					end = end.saturating_add(1u32.into());
+				}
+				ConfirmationEnd::<T>::insert(end, proposal_id);
+
+				Self::set_status(proposal_id, proposal, ProposalStatus::Confirming);
+				proposal.confirm_end = Some(end);
+				Self::deposit_event(Event::ConfirmStarted(proposal_id, end));
+			},
+			ProposalStatus::Confirming if !passing => {
+				if let Some(end) = proposal.confirm_end.take() {
+					ConfirmationEnd::<T>::remove(end);
+				}
+				Self::set_status(proposal_id, proposal, ProposalStatus::VotingInProgress);
+				Self::deposit_event(Event::ConfirmAborted(proposal_id));
+			},
+			_ => {},
+		}
+	}
+
+	/// Accept a proposal whose confirmation period ended on `block`. Returns the weight consumed.
+	pub(crate) fn confirm_proposal(proposal_id: T::ProposalId, block: BlockNumberFor<T>) -> Weight {
+		let Some(mut proposal) = Proposals::<T>::get(proposal_id) else {
+			return T::DbWeight::get().reads(1);
+		};
+		if !proposal.is_active
+			|| proposal.status != ProposalStatus::Confirming
+			|| proposal.confirm_end != Some(block)
+		{
+			return T::DbWeight::get().reads(1);
+		}
+
+		proposal.confirm_end = None;
+		Self::close_early(proposal_id, &mut proposal, ProposalStatus::Accepted, false);
+		Proposals::<T>::insert(proposal_id, &proposal);
+
+		Self::deposit_event(Event::ProposalConfirmed(proposal_id));
+		T::WeightInfo::close_proposal(proposal.voter_accounts.len() as u32)
+	}
+
+	/// Act on a deciding `proposal` beating the approval and support curves at the current
+	/// block: it starts confirming on a track with a confirmation period, or else passes.
+	/// Otherwise a check is scheduled for the block its tally beats the curves from.
+	///
+	/// Returns whether the proposal passed, leaving closing it to the caller.
+	pub(crate) fn update_passing(proposal_id: T::ProposalId, proposal: &mut ProposalOf<T>) -> bool {
+		// Competing proposals are only decided together at the deadline of their group.
+		if ProposalGroup::<T>::contains_key(proposal_id) {
+			return false;
+		}
+
+		Self::update_confirmation(proposal_id, proposal);
+
+		if !Self::uses_curves() || proposal.candle_period.is_some() {
+			return false;
+		}
+
+		// A proposal still deciding on a track with a confirmation period has to confirm first.
+		let confirms =
+			T::Tracks::info(proposal.track).map_or(false, |track| !track.confirm_period.is_zero());
+		let now = frame_system::Pallet::<T>::block_number();
+		if proposal.status == ProposalStatus::VotingInProgress
+			&& !confirms
+			&& Self::is_passing(proposal_id, proposal, now)
+		{
+			return true;
+		}
+		Self::schedule_curve_check(proposal_id, proposal);
+		false
+	}
+
+	/// Schedule a check of a deciding `proposal` against the approval and support curves at the
+	/// first block after the current one its tally beats them at, if that is before its
+	/// deadline. Replaces any check scheduled before. No check is scheduled if none of the
+	/// `MAX_EXPIRY_PROBES` blocks from there is free; the proposal is then decided on its next
+	/// vote or at its deadline.
+	pub(crate) fn schedule_curve_check(proposal_id: T::ProposalId, proposal: &mut ProposalOf<T>) {
+		if let Some(block) = proposal.curve_check.take() {
+			CurveCheck::<T>::remove(block);
+		}
+
+		if !Self::uses_curves()
+			|| proposal.candle_period.is_some()
+			|| proposal.status != ProposalStatus::VotingInProgress
+		{
+			return;
+		}
+
+		// Thresholds only fall over time, so once the tally beats the curves it keeps doing so.
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut low = now.saturating_add(One::one());
+		let mut high = proposal.deadline.saturating_sub(One::one());
+		if low > high || !Self::is_passing(proposal_id, proposal, high) {
+			return;
+		}
+		while low < high {
+			let middle = low.saturating_add(high.saturating_sub(low) / 2u32.into());
+			if Self::is_passing(proposal_id, proposal, middle) {
+				high = middle;
+			} else {
+				low = middle.saturating_add(One::one());
+			}
+		}
+
+		let mut block = high;
+		let mut probes = 0;
+		while CurveCheck::<T>::contains_key(block) {
+			probes += 1;
+			if probes >= MAX_EXPIRY_PROBES {
+				return;
+			}
+			block = block.saturating_add(1u32.into());
+		}
+		CurveCheck::<T>::insert(block, proposal_id);
+		proposal.curve_check = Some(block);
+	}
+
+	/// Check a proposal scheduled for `block` against the approval and support curves. Returns
+	/// the weight consumed.
+	pub(crate) fn check_curves(proposal_id: T::ProposalId, block: BlockNumberFor<T>) -> Weight {
+		let Some(mut proposal) = Proposals::<T>::get(proposal_id) else {
+			return T::DbWeight::get().reads(1);
+		};
+		if !proposal.is_active || proposal.curve_check != Some(block) {
+			return T::DbWeight::get().reads(1);
+		}
+
+		proposal.curve_check = None;
+		let passed = Self::update_passing(proposal_id, &mut proposal);
+		if passed {
+			Self::close_early(proposal_id, &mut proposal, ProposalStatus::Accepted, false);
+		}
+		Proposals::<T>::insert(proposal_id, &proposal);
+
+		if passed {
+			Self::deposit_event(Event::ProposalPassedEarly(proposal_id));
+		}
+		T::WeightInfo::close_proposal(proposal.voter_accounts.len() as u32)
+	}
+
+	/// Close the vote on the amendment of `proposal_id`, revising the proposal if the amendment
+	/// was accepted. Returns the weight consumed.
+	pub(crate) fn close_amendment(proposal_id: T::ProposalId) -> Weight {
+		let Some(amendment) = Amendments::<T>::take(proposal_id) else {
+			return T::DbWeight::get().reads(1);
+		};
+
+		let accepted = amendment.is_accepted(T::MinAmendmentVotes::get());
+		Self::deposit_event(Event::AmendmentClosed(proposal_id, accepted));
+		let mut voters = 0;
+		if accepted {
+			voters = Self::revise(
+				proposal_id,
+				amendment.proposer,
+				amendment.description,
+				amendment.reset_votes,
+			);
+		}
+		T::WeightInfo::close_amendment(voters)
+	}
+
+	/// Replace the description of an open proposal with a new revision by `author`, recording
+	/// it in the revision history. With `reset_votes` the votes cast so far are cleared, and the
+	/// number of votes cleared is returned.
+	pub(crate) fn revise(
+		proposal_id: T::ProposalId,
+		author: T::AccountId,
+		description: BoundedVec<u8, <T as pallet::Config>::DescriptionLimit>,
+		reset_votes: bool,
+	) -> u32 {
+		let Some(mut proposal) = Proposals::<T>::get(proposal_id) else { return 0 };
+		if !proposal.is_active {
+			return 0;
+		}
+
+		let name = proposal.name.clone();
+		let content_hash = T::Hashing::hash_of(&(&name, &description));
+		let Ok(revision) = Self::record_revision(
+			proposal_id,
+			&mut proposal,
+			author,
+			name,
+			description,
+			content_hash,
+		) else {
+			return 0;
+		};
+
+		// Voting on the revision starts over from an empty tally.
+		let mut passed = false;
+		let mut voters = 0;
+		if reset_votes {
+			voters = proposal.voter_accounts.len() as u32;
+			Self::forget_votes(proposal_id, &proposal.voter_accounts);
+			proposal.voter_accounts = Default::default();
+			proposal.in_support = Default::default();
+			proposal.in_oppose = Default::default();
+			proposal.last_lead = Default::default();
+			let _ = CandleCheckpoints::<T>::clear_prefix(proposal_id, u32::MAX, None);
+			Self::checkpoint_tally(proposal_id, &proposal);
+
+			passed = Self::update_passing(proposal_id, &mut proposal);
+			if passed {
+				Self::close_early(proposal_id, &mut proposal, ProposalStatus::Accepted, false);
+			}
+		}
+		Proposals::<T>::insert(proposal_id, &proposal);
+
+		Self::deposit_event(Event::ProposalRevised(
+			proposal_id,
+			revision,
+			content_hash,
+			reset_votes,
+		));
+		if passed {
+			Self::deposit_event(Event::ProposalPassedEarly(proposal_id));
+		}
+		voters
+	}
+
+	/// Give `proposal` the new `name` and `description` as its next revision by `author`,
+	/// recording the revision with `content_hash` in its history. Returns the revision number.
+	pub(crate) fn record_revision(
+		proposal_id: T::ProposalId,
+		proposal: &mut ProposalOf<T>,
+		author: T::AccountId,
+		name: BoundedVec<u8, <T as pallet::Config>::NameLimit>,
+		description: BoundedVec<u8, <T as pallet::Config>::DescriptionLimit>,
+		content_hash: T::Hash,
+	) -> Result<u32, DispatchError> {
+		let at = frame_system::Pallet::<T>::block_number();
+		Revisions::<T>::try_mutate(proposal_id, |revisions| {
+			revisions
+				.try_push(Revision { author, content_hash, at })
+				.map_err(|_| Error::<T>::TooManyRevisions)
+		})?;
+
+		proposal.name = name;
+		proposal.description = description;
+		proposal.revision = proposal.revision.saturating_add(1);
+		Ok(proposal.revision)
+	}
+
+	/// Add `proposal_id` to the group of competing proposals `rival_id` belongs to, forming the
+	/// group around `rival_id` if it has none. Returns the group and its deadline.
+	pub(crate) fn join_group(
+		rival_id: T::ProposalId,
+		proposal_id: T::ProposalId,
+	) -> Result<(T::ProposalId, BlockNumberFor<T>), DispatchError> {
+		let mut rival = Proposals::<T>::get(rival_id).ok_or(Error::<T>::InvalidGroup)?;
+		ensure!(
+			rival.is_active && rival.anti_sniping.is_none() && rival.candle_period.is_none(),
+			Error::<T>::InvalidGroup
+		);
+
+		let group_id = ProposalGroup::<T>::get(rival_id).unwrap_or(rival_id);
+		Groups::<T>::try_mutate(group_id, |members| -> DispatchResult {
+			if members.is_empty() {
+				members
+					.try_push(rival_id)
+					.map_err(|_| Error::<T>::GroupFull)?;
+			}
+			members
+				.try_push(proposal_id)
+				.map_err(|_| Error::<T>::GroupFull)?;
+			Ok(())
+		})?;
+
+		// A proposal forming a group no longer closes early.
+		if group_id == rival_id && !ProposalGroup::<T>::contains_key(rival_id) {
+			ProposalGroup::<T>::insert(rival_id, group_id);
+			if let Some(end) = rival.confirm_end.take() {
+				ConfirmationEnd::<T>::remove(end);
+			}
+			if let Some(block) = rival.curve_check.take() {
+				CurveCheck::<T>::remove(block);
+			}
+			if rival.status == ProposalStatus::Confirming {
+				Self::set_status(rival_id, &mut rival, ProposalStatus::VotingInProgress);
+			}
+			Proposals::<T>::insert(rival_id, &rival);
+		}
+		ProposalGroup::<T>::insert(proposal_id, group_id);
+
+		Ok((group_id, rival.deadline))
+	}
+
+	/// Close the proposals competing in `group` at block `at`. Of those that would be accepted,
+	/// only the one with the widest approval margin over its threshold is: the others are
+	/// superseded. Returns the weight consumed.
+	pub(crate) fn close_group(group: T::ProposalId, at: BlockNumberFor<T>) -> Weight {
+		let members = Groups::<T>::take(group);
+		let size = members.len() as u32;
+		let mut voters = 0u32;
+		let mut results = Vec::new();
+		for proposal_id in members {
+			ProposalGroup::<T>::remove(proposal_id);
+			let Some(proposal) = Proposals::<T>::get(proposal_id) else { continue };
+			if !proposal.is_active {
+				continue;
+			}
+
+			let (status, tied, margin) = if proposal.status == ProposalStatus::Preparing {
+				(ProposalStatus::Rejected, false, Perbill::zero())
+			} else {
+				let (support, oppose) = proposal.tally();
+				let (status, tied) = Self::outcome(proposal_id, &proposal, support, oppose, at);
+				(status, tied, Self::margin(&proposal, support, oppose, at))
+			};
+			voters.saturating_accrue(proposal.voter_accounts.len() as u32);
+			results.push((proposal_id, proposal, status, tied, margin));
+		}
+
+		// Equal margins go to the proposal with more support, then to the one created first.
+		let winner = results
+			.iter()
+			.enumerate()
+			.filter(|(_, (_, _, status, _, _))| *status == ProposalStatus::Accepted)
+			.max_by_key(|(index, (_, proposal, _, _, margin))| {
+				(*margin, proposal.in_support.len(), Reverse(*index))
+			})
+			.map(|(index, _)| index);
+
+		for (index, (proposal_id, mut proposal, status, tied, _)) in results.into_iter().enumerate()
+		{
+			let status = if status == ProposalStatus::Accepted && Some(index) != winner {
+				ProposalStatus::Superseded
+			} else {
+				status
+			};
+			Self::set_status(proposal_id, &mut proposal, status.clone());
+			proposal.is_active = false;
+			Proposals::<T>::insert(proposal_id, &proposal);
+			let root = Self::commit_voters(proposal_id, &proposal);
+
+			Self::deposit_event(Event::ProposalClosed(proposal_id, status, tied, root));
+
+			Self::finish_proposal(proposal_id, &proposal);
+		}
+		T::WeightInfo::close_group(size, voters)
+	}
+
+	/// How far the approval of `proposal` on a `(support, oppose)` tally is above its approval
+	/// threshold at block `at`.
+	pub(crate) fn margin(
+		proposal: &ProposalOf<T>,
+		support: u32,
+		oppose: u32,
+		at: BlockNumberFor<T>,
+	) -> Perbill {
+		let Some((_, threshold)) = Self::thresholds(proposal, at) else { return Perbill::zero() };
+
+		let turnout = support.saturating_add(oppose);
+		if turnout.is_zero() {
+			return Perbill::zero();
+		}
+		Perbill::from_rational(support, turnout).saturating_sub(threshold)
+	}
+
+	/// Close `proposal` before its deadline with the given `status`, as expiry would.
+	pub(crate) fn close_early(
+		proposal_id: T::ProposalId,
+		proposal: &mut ProposalOf<T>,
+		status: ProposalStatus,
+		tied: bool,
+	) {
+		Self::set_status(proposal_id, proposal, status.clone());
+		proposal.is_active = false;
+		Self::unschedule_expiry(proposal_id, proposal.deadline);
+		Self::finish_proposal(proposal_id, proposal);
+
+		let root = Self::commit_voters(proposal_id, proposal);
+		Self::deposit_event(Event::ProposalClosed(proposal_id, status, tied, root));
+	}
+
+	/// Commit to the final voter set of `proposal`, storing the Merkle root over its votes.
+	pub(crate) fn commit_voters(proposal_id: T::ProposalId, proposal: &ProposalOf<T>) -> T::Hash {
+		let root = binary_merkle_tree::merkle_root::<T::Hashing, _>(Self::voter_leaves(proposal));
+		VoterRoots::<T>::insert(proposal_id, root);
+		root
+	}
+
+	/// Leaves of the voter set of `proposal`: its encoded `(account, choice, weight)` votes, in
+	/// the order they were cast.
+	fn voter_leaves(proposal: &ProposalOf<T>) -> Vec<Vec<u8>> {
+		proposal
+			.voter_accounts
+			.iter()
+			.filter_map(|who| Some((who, proposal.vote_of(who)?, VOTE_WEIGHT).encode()))
+			.collect()
+	}
+
+	/// Record the current tally of `proposal` in its checkpoints, replacing the checkpoint of the
+	/// current block if there is one.
+	pub(crate) fn checkpoint_tally(proposal_id: T::ProposalId, proposal: &ProposalOf<T>) {
+		let now = frame_system::Pallet::<T>::block_number();
+		let (support, oppose) = proposal.tally();
+		TallyCheckpoints::<T>::mutate(proposal_id, |checkpoints| {
+			if checkpoints
+				.last()
+				.map_or(false, |(block, _, _)| *block == now)
+			{
+				checkpoints.pop();
+			}
+			if checkpoints.is_full() {
+				let mut keep = false;
+				checkpoints.retain(|_| {
+					keep = !keep;
+					keep
+				});
+			}
+			let _ = checkpoints.try_push((now, support, oppose));
+		});
+	}
+
+	/// Record the running tally of a candle-ending proposal once its ending period has begun.
+	/// `previous_tally` is the tally before the vote that was just added.
+	pub(crate) fn checkpoint_candle(
+		proposal_id: T::ProposalId,
+		proposal: &ProposalOf<T>,
+		previous_tally: (u32, u32),
+	) {
+		let Some(period) = proposal.candle_period else { return };
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let ending_start = proposal.deadline.saturating_sub(period);
+		let first_checkpoint = CandleCheckpoints::<T>::iter_prefix_values(proposal_id)
+			.next()
+			.is_none();
+		if now < ending_start && first_checkpoint {
+			return;
+		}
+
+		// Keep the tally the ending period started with, if it is not the one of this block.
+		if first_checkpoint && now > ending_start {
+			CandleCheckpoints::<T>::insert(proposal_id, ending_start, previous_tally);
+		}
+		CandleCheckpoints::<T>::insert(proposal_id, now, proposal.tally());
+	}
+
+	/// The `(support, oppose)` tally a closing proposal is decided on.
+	///
+	/// For candle-ending proposals this draws the effective close from the ending period and
+	/// returns the tally as of that block.
+	pub(crate) fn closing_tally(
+		proposal_id: T::ProposalId,
+		proposal: &ProposalOf<T>,
+	) -> (u32, u32) {
+		let Some(period) = proposal.candle_period else { return proposal.tally() };
+
+		let (random, _) = T::Randomness::random(&(b"candle", proposal_id).encode());
+		let seed = u32::decode(&mut TrailingZeroInput::new(random.as_ref())).unwrap_or_default();
+		let offset = seed % period.saturated_into::<u32>().max(1);
+
+		let ending_start = proposal.deadline.saturating_sub(period);
+		let candle_block = ending_start.saturating_add(offset.into());
+
+		let tally = Self::candle_tally(proposal_id, proposal, candle_block);
+		let _ = CandleCheckpoints::<T>::clear_prefix(proposal_id, u32::MAX, None);
+
+		Self::deposit_event(Event::CandleEnded(proposal_id, candle_block));
+		tally
+	}
+
+	/// The `(support, oppose)` tally of candle-ending `proposal` as of the end of block
+	/// `candle_block`.
+	pub(crate) fn candle_tally(
+		proposal_id: T::ProposalId,
+		proposal: &ProposalOf<T>,
+		candle_block: BlockNumberFor<T>,
+	) -> (u32, u32) {
+		// Without checkpoints no vote was cast in the ending period.
+		CandleCheckpoints::<T>::iter_prefix(proposal_id)
+			.filter(|(block, _)| *block <= candle_block)
+			.max_by_key(|(block, _)| *block)
+			.map(|(_, tally)| tally)
+			.unwrap_or_else(|| proposal.tally())
+	}
+
+	/// Push the deadline of `proposal` back if a vote changed the leading side inside its
+	/// anti-sniping window. Returns the old and new deadline if it was moved.
+	pub(crate) fn apply_anti_sniping(
+		proposal_id: T::ProposalId,
+		proposal: &mut ProposalOf<T>,
+	) -> Option<(BlockNumberFor<T>, BlockNumberFor<T>)> {
+		let settings = proposal.anti_sniping.as_ref()?;
+
+		let now = frame_system::Pallet::<T>::block_number();
+		if proposal.deadline.saturating_sub(now) > settings.window {
+			return None;
+		}
+
+		// Never past the maximum duration of the track, whatever `max_extension` allows.
+		let limit = proposal
+			.created_at
+			.saturating_add(Self::max_duration(proposal.track))
+			.saturating_sub(proposal.deadline);
+		let remaining = settings
+			.max_extension
+			.saturating_sub(proposal.auto_extended);
+		let extension = settings.extension.min(remaining).min(limit);
+		if extension.is_zero() {
+			return None;
+		}
+
+		let old_deadline = proposal.deadline;
+		let new_deadline = Self::reschedule_expiry(
+			proposal_id,
+			old_deadline,
+			old_deadline.saturating_add(extension),
+		)
+		.ok()?;
+		proposal.deadline = new_deadline;
+		proposal.auto_extended = proposal.auto_extended.saturating_add(extension);
+
+		Some((old_deadline, new_deadline))
+	}
+
+	/// Move the expiry of `proposal_id` from `old_block` to `new_block`, returning the block it
+	/// ends up scheduled at. The expiry is left at `old_block` if no block is free.
+	pub(crate) fn reschedule_expiry(
+		proposal_id: T::ProposalId,
+		old_block: BlockNumberFor<T>,
+		new_block: BlockNumberFor<T>,
+	) -> Result<BlockNumberFor<T>, DispatchError> {
+		let expire_block = Self::free_expiry_slot(proposal_id, new_block)?;
+		Self::unschedule_expiry(proposal_id, old_block);
+		ProposalExpireTime::<T>::insert(expire_block, proposal_id);
+		Ok(expire_block)
+	}
+
+	/// Remove the expiry of `proposal_id` at `block`, if it is scheduled there.
+	pub(crate) fn unschedule_expiry(proposal_id: T::ProposalId, block: BlockNumberFor<T>) {
+		if ProposalExpireTime::<T>::get(block) == Some(proposal_id) {
+			ProposalExpireTime::<T>::remove(block);
+		}
+	}
+}
+
+// Queries backing the runtime API.
+impl<T: Config> Pallet<T> {
+	/// Proposal `proposal_id` with its current tally and a page of its voters.
+	pub fn proposal_details(
+		proposal_id: T::ProposalId,
+		voters: Page,
+	) -> Option<ProposalDetailsOf<T>> {
+		let proposal = Proposals::<T>::get(proposal_id)?;
+		let voters = proposal
+			.voter_accounts
+			.iter()
+			.skip(voters.offset as usize)
+			.take(voters.size())
+			.filter_map(|who| Some((who.clone(), proposal.vote_of(who)?)))
+			.collect();
+		Some(Self::details(&proposal, voters))
+	}
+
+	/// A page of the active proposals with their current tally, without their voters.
+	pub fn active_proposals(page: Page) -> Vec<(T::ProposalId, ProposalDetailsOf<T>)> {
+		Proposals::<T>::iter()
+			.filter(|(_, proposal)| proposal.is_active)
+			.skip(page.offset as usize)
+			.take(page.size())
+			.map(|(proposal_id, proposal)| (proposal_id, Self::details(&proposal, Vec::new())))
+			.collect()
+	}
+
+	/// A page of the proposals meeting `filter`, with their current tally, without their voters.
+	pub fn proposals(
+		filter: ProposalFilterOf<T>,
+		page: Page,
+	) -> Vec<(T::ProposalId, ProposalDetailsOf<T>)> {
+		Proposals::<T>::iter()
+			.filter(|(_, proposal)| {
+				filter.matches(&proposal.status, &proposal.owner, &proposal.track)
+			})
+			.skip(page.offset as usize)
+			.take(page.size())
+			.map(|(proposal_id, proposal)| (proposal_id, Self::details(&proposal, Vec::new())))
+			.collect()
+	}
+
+	/// Up to `limit` proposals meeting `query`, with their current tally, without their voters,
+	/// continuing from `cursor` if given. Candidates are drawn from the owner, status or creation
+	/// index when the query allows, and at most `MAX_SEARCH_SCAN` of them are scanned per call.
+	/// A `limit` of zero returns a full page of `MAX_PAGE_SIZE` results.
+	pub fn search(
+		query: ProposalQueryOf<T>,
+		cursor: Option<Vec<u8>>,
+		limit: u32,
+	) -> SearchResultOf<T> {
+		let limit = if limit.is_zero() { MAX_PAGE_SIZE } else { limit.min(MAX_PAGE_SIZE) };
+		let limit = limit as usize;
+		let mut next = cursor.clone();
+		let mut candidates = Self::search_candidates(&query, cursor);
+
+		let mut proposals = Vec::new();
+		for _ in 0..MAX_SEARCH_SCAN {
+			if proposals.len() == limit {
+				break;
+			}
+			let Some((proposal_id, raw_key)) = candidates.next() else {
+				return SearchResult { proposals, next: None };
+			};
+			next = Some(raw_key);
+
+			let Some(proposal) = Proposals::<T>::get(proposal_id) else { continue };
+			let matches = query
+				.name
+				.as_ref()
+				.map_or(true, |name| name.matches(&proposal.name))
+				&& query
+					.owner
+					.as_ref()
+					.map_or(true, |owner| *owner == proposal.owner)
+				&& query
+					.status
+					.as_ref()
+					.map_or(true, |status| *status == proposal.status)
+				&& query
+					.created_from
+					.map_or(true, |from| proposal.created_at >= from)
+				&& query
+					.created_to
+					.map_or(true, |to| proposal.created_at <= to);
+			if matches {
+				proposals.push((proposal_id, Self::details(&proposal, Vec::new())));
+			}
+		}
+		SearchResult { proposals, next }
+	}
+
+	/// Ids of the proposals a search for `query` scans, after `cursor` if given, each with the
+	/// raw storage key it was found at.
+	fn search_candidates(
+		query: &ProposalQueryOf<T>,
+		cursor: Option<Vec<u8>>,
+	) -> Box<dyn Iterator<Item = (T::ProposalId, Vec<u8>)>> {
+		if let Some(owner) = query.owner.clone() {
+			let ids = match cursor {
+				Some(cursor) => ProposalsByOwner::<T>::iter_key_prefix_from(&owner, cursor),
+				None => ProposalsByOwner::<T>::iter_key_prefix(&owner),
+			};
+			return Box::new(ids.map(move |proposal_id| {
+				(proposal_id, ProposalsByOwner::<T>::hashed_key_for(&owner, proposal_id))
+			}));
+		}
+
+		if let Some(status) = query.status.clone() {
+			let ids = match cursor {
+				Some(cursor) => ProposalsByStatus::<T>::iter_key_prefix_from(&status, cursor),
+				None => ProposalsByStatus::<T>::iter_key_prefix(&status),
+			};
+			return Box::new(ids.map(move |proposal_id| {
+				(proposal_id, ProposalsByStatus::<T>::hashed_key_for(&status, proposal_id))
+			}));
+		}
+
+		if query.created_from.is_some() || query.created_to.is_some() {
+			// Any key of a block at or after `created_from` sorts after the prefix of that block.
+			let start = cursor.unwrap_or_else(|| {
+				let from = query
+					.created_from
+					.map(Self::creation_key)
+					.unwrap_or_default();
+				[&ProposalsByCreation::<T>::final_prefix()[..], &from[..]].concat()
+			});
+			let to = query.created_to.map_or([u8::MAX; 8], Self::creation_key);
+			return Box::new(
+				ProposalsByCreation::<T>::iter_keys_from(start)
+					.take_while(move |(block, _)| *block <= to)
+					.map(|(block, proposal_id)| {
+						(proposal_id, ProposalsByCreation::<T>::hashed_key_for(block, proposal_id))
+					}),
+			);
+		}
+
+		let ids = match cursor {
+			Some(cursor) => Proposals::<T>::iter_keys_from(cursor),
+			None => Proposals::<T>::iter_keys(),
+		};
+		Box::new(ids.map(|proposal_id| (proposal_id, Proposals::<T>::hashed_key_for(proposal_id))))
+	}
+
+	/// A page of the most recent proposals `who` voted on, newest first, with how they voted.
+	pub fn votes_of(who: &T::AccountId, page: Page) -> Vec<(T::ProposalId, Vote)> {
+		VotesByAccount::<T>::get(who)
+			.into_iter()
+			.rev()
+			.skip(page.offset as usize)
+			.take(page.size())
+			.collect()
+	}
+
+	/// Merkle proof that `who` is part of the voter set proposal `proposal_id` committed to when
+	/// it closed, if it has closed and `who` voted on it.
+	pub fn voter_proof(
+		proposal_id: T::ProposalId,
+		who: &T::AccountId,
+	) -> Option<VoterProof<T::Hash>> {
+		let root = VoterRoots::<T>::get(proposal_id)?;
+		let proposal = Proposals::<T>::get(proposal_id)?;
+		let choice = proposal.vote_of(who)?;
+		let leaf = (who, choice.clone(), VOTE_WEIGHT).encode();
+		let leaves = Self::voter_leaves(&proposal);
+		let leaf_index = leaves.iter().position(|other| *other == leaf)?;
+
+		let proof = binary_merkle_tree::merkle_proof::<T::Hashing, _, _>(leaves, leaf_index);
+		Some(VoterProof {
+			root,
+			choice,
+			weight: VOTE_WEIGHT,
+			proof: proof.proof,
+			number_of_leaves: proof.number_of_leaves as u32,
+			leaf_index: leaf_index as u32,
+		})
+	}
+
+	/// Whether `proof` shows that `who` is part of the voter set committed to by its root.
+	pub fn verify_voter_proof(who: &T::AccountId, proof: &VoterProof<T::Hash>) -> bool {
+		let leaf = (who, proof.choice.clone(), proof.weight).encode();
+		binary_merkle_tree::verify_proof::<T::Hashing, _, _>(
+			&proof.root,
+			proof.proof.iter().copied(),
+			proof.number_of_leaves as usize,
+			proof.leaf_index as usize,
+			&leaf,
+		)
+	}
+
+	/// A page of the active proposals with the outcome each of them would have if it closed
+	/// now. Proposals are projected on their own, regardless of the group they compete in.
+	pub fn projected_outcomes(page: Page) -> Vec<(T::ProposalId, Projection)> {
+		let now = frame_system::Pallet::<T>::block_number();
+		Proposals::<T>::iter()
+			.filter(|(_, proposal)| proposal.is_active)
+			.skip(page.offset as usize)
+			.take(page.size())
+			.map(|(proposal_id, proposal)| {
+				(proposal_id, Self::projection(proposal_id, &proposal, now))
+			})
+			.collect()
+	}
+
+	/// Proposals created, voted on, moved to another status or closed in the current block, in
+	/// the order of their events, with their state at the end of the block. Every close, be it at
+	/// the deadline, early, on confirmation or by cancellation, is reported as `Closed`.
+	pub fn updates() -> Vec<ProposalUpdateOf<T>>
+	where
+		<T as Config>::RuntimeEvent: TryInto<Event<T>>,
+	{
+		frame_system::Pallet::<T>::read_events_no_consensus()
+			.filter_map(|record| {
+				let event: <T as Config>::RuntimeEvent = record.event.into();
+				let (proposal_id, change) = match event.try_into().ok()? {
+					Event::CreatedProposal(proposal_id) => (proposal_id, ProposalChange::Created),
+					Event::VoteCasted(proposal_id) => (proposal_id, ProposalChange::VoteCast),
+					Event::DecisionDepositPlaced(id, ..)
+					| Event::ProposalLaunched(id, _)
+					| Event::ConfirmStarted(id, _)
+					| Event::ConfirmAborted(id) => (id, ProposalChange::StatusChanged),
+					Event::ProposalClosed(proposal_id, status, tied, _) => {
+						(proposal_id, ProposalChange::Closed { status, tied })
+					},
+					_ => return None,
+				};
+				let proposal = Proposals::<T>::get(proposal_id)?;
+				let details = Self::details(&proposal, Vec::new());
+				Some(ProposalUpdate {
+					proposal_id,
+					change,
+					owner: details.owner,
+					track: details.track,
+					status: details.status,
+					tally: details.tally,
+				})
+			})
+			.collect()
+	}
+
+	/// Checks whether `who` voting `choice` on proposal `proposal_id` would succeed, without
+	/// keeping the vote. Returns the name of the error the vote would fail with.
+	pub fn validate_vote(
+		proposal_id: T::ProposalId,
+		who: T::AccountId,
+		choice: Vote,
+	) -> Result<(), Vec<u8>>
+	where
+		<T as Config>::RuntimeEvent: TryInto<Event<T>>,
+	{
+		Self::simulate_votes(proposal_id, sp_std::vec![(who, choice)], None).map(|_| ())
+	}
+
+	/// Outcome proposal `proposal_id` would close with at its deadline if `votes` were cast now,
+	/// in order, and no other vote after them. None of the votes is kept. Returns the name of the
+	/// error the first failing vote would fail with.
+	///
+	/// A candle-ending proposal is decided on its tally as of `candle_block`, taken within its
+	/// ending period, rather than on a randomly drawn block. It defaults to the deadline, so that
+	/// every vote counts.
+	pub fn simulate_votes(
+		proposal_id: T::ProposalId,
+		votes: Vec<(T::AccountId, Vote)>,
+		candle_block: Option<BlockNumberFor<T>>,
+	) -> Result<Simulation, Vec<u8>>
+	where
+		<T as Config>::RuntimeEvent: TryInto<Event<T>>,
+	{
+		with_transaction(|| {
+			let simulation = votes
+				.into_iter()
+				.try_for_each(|(who, choice)| {
+					let origin = frame_system::RawOrigin::Signed(who).into();
+					Self::vote(origin, proposal_id, choice)
+						.map(|_| ())
+						.map_err(|e| e.error)
+				})
+				.and_then(|()| {
+					let proposal =
+						Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalDoesNotExist)?;
+					Ok(Self::simulation(proposal_id, &proposal, candle_block))
+				});
+			TransactionOutcome::Rollback(simulation)
+		})
+		.map_err(|e: DispatchError| <&'static str>::from(e).as_bytes().to_vec())
+	}
+
+	/// Outcome `proposal` closes with at its deadline, as decided on expiry, deciding a candle
+	/// ending on the tally as of `candle_block`. If it is no longer active, the outcome it closed
+	/// with instead, tied if it closed on a tie in this block.
+	fn simulation(
+		proposal_id: T::ProposalId,
+		proposal: &ProposalOf<T>,
+		candle_block: Option<BlockNumberFor<T>>,
+	) -> Simulation
+	where
+		<T as Config>::RuntimeEvent: TryInto<Event<T>>,
+	{
+		let (status, tied) = if !proposal.is_active {
+			let tied = frame_system::Pallet::<T>::read_events_no_consensus()
+				.filter_map(|record| {
+					let event: <T as Config>::RuntimeEvent = record.event.into();
+					match event.try_into().ok()? {
+						Event::ProposalClosed(id, _, tied, _) if id == proposal_id => Some(tied),
+						_ => None,
+					}
+				})
+				.last()
+				.unwrap_or(false);
+			(proposal.status.clone(), tied)
+		} else if matches!(
+			proposal.status,
+			ProposalStatus::Preparing | ProposalStatus::AwaitingCoAuthors | ProposalStatus::Queued
+		) {
+			(ProposalStatus::Rejected, false)
+		} else {
+			let (support, oppose) = match proposal.candle_period {
+				Some(period) => {
+					let ending_start = proposal.deadline.saturating_sub(period);
+					let candle_block = candle_block
+						.unwrap_or(proposal.deadline)
+						.clamp(ending_start, proposal.deadline);
+					Self::candle_tally(proposal_id, proposal, candle_block)
+				},
+				None => proposal.tally(),
+			};
+			Self::outcome(proposal_id, proposal, support, oppose, proposal.deadline)
+		};
+		Simulation { status, tied, tally: Self::details(proposal, Vec::new()).tally }
+	}
+
+	/// Outcome `proposal` would have if it closed at block `at`.
+	pub(crate) fn projection(
+		proposal_id: T::ProposalId,
+		proposal: &ProposalOf<T>,
+		at: BlockNumberFor<T>,
+	) -> Projection {
+		let (min_turnout, approval_threshold) = Self::thresholds(proposal, at).unwrap_or_default();
+		let (status, tied) = if proposal.status == ProposalStatus::Preparing {
+			(ProposalStatus::Rejected, false)
+		} else {
+			let (support, oppose) = proposal.tally();
+			Self::outcome(proposal_id, proposal, support, oppose, at)
+		};
+		Projection { status, tied, min_turnout, approval_threshold }
+	}
+
+	/// `proposal` as returned by the runtime API, with the given page of `voters`.
+	pub(crate) fn details(
+		proposal: &ProposalOf<T>,
+		voters: Vec<(T::AccountId, Vote)>,
+	) -> ProposalDetailsOf<T> {
+		let (support, oppose) = proposal.tally();
+		let turnout = support.saturating_add(oppose);
+		ProposalDetails {
+			owner: proposal.owner.clone(),
+			co_authors: proposal.co_authors.to_vec(),
+			name: proposal.name.to_vec(),
+			description: proposal.description.to_vec(),
+			track: proposal.track,
+			status: proposal.status.clone(),
+			is_active: proposal.is_active,
+			created_at: proposal.created_at,
+			deadline: proposal.deadline,
+			revision: proposal.revision,
+			tally: Tally {
+				support,
+				oppose,
+				turnout: Perbill::from_rational(turnout, Self::eligible_voters(proposal).max(1)),
+				approval: Perbill::from_rational(support, turnout.max(1)),
+			},
+			voters,
+		}
 	}
 }
@@ -0,0 +1,132 @@
+// Storage migrations of the proposal pallet.
+
+use crate::{
+	constants::{BLOCKS_PER_DAY, PROPOSAL_DURATION_LIMIT},
+	pallet::{
+		ActiveProposalCount, Config, Pallet, ProposalExpireTime, Proposals, ProposalsByCreation,
+		ProposalsByOwner, ProposalsByStatus,
+	},
+	tracks::TracksInfo,
+	types::{Proposal, ProposalStatus},
+};
+use codec::{Decode, Encode};
+use frame_support::{
+	migrations::VersionedMigration,
+	pallet_prelude::Get,
+	sp_runtime::traits::{Saturating, Zero},
+	traits::OnRuntimeUpgrade,
+	weights::Weight,
+	BoundedVec,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData};
+
+/// Migration of proposals stored before tracks, deadlines and the other proposal fields were
+/// added.
+pub mod v1 {
+	use super::*;
+
+	/// Proposal as stored at storage version 0.
+	#[derive(Encode, Decode)]
+	pub struct OldProposal<
+		AccountId,
+		NameLimit: Get<u32>,
+		DescriptionLimit: Get<u32>,
+		AccountLimit: Get<u32>,
+	> {
+		pub owner: AccountId,
+		pub name: BoundedVec<u8, NameLimit>,
+		pub description: BoundedVec<u8, DescriptionLimit>,
+		pub is_active: bool,
+		pub voter_accounts: BoundedVec<AccountId, AccountLimit>,
+		pub in_support: BoundedVec<AccountId, AccountLimit>,
+		pub in_oppose: BoundedVec<AccountId, AccountLimit>,
+		pub status: ProposalStatus,
+	}
+
+	pub type OldProposalOf<T> = OldProposal<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::NameLimit,
+		<T as Config>::DescriptionLimit,
+		<T as Config>::AccountLimit,
+	>;
+
+	/// Translates every proposal to the current layout, on the first track and with the deadline it
+	/// is scheduled to expire at, and builds the proposal indexes. Version 0 kept neither the
+	/// creation block nor the duration, so a proposal is taken to have been created the longest
+	/// allowed duration before its deadline. Use [`MigrateV0ToV1`].
+	pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let Some((track, _)) = T::Tracks::tracks().first() else {
+				return T::DbWeight::get().reads(1);
+			};
+			let now = frame_system::Pallet::<T>::block_number();
+			let longest: BlockNumberFor<T> = (BLOCKS_PER_DAY * PROPOSAL_DURATION_LIMIT).into();
+
+			// Deadlines were only kept in the expiry schedule.
+			let deadlines: BTreeMap<_, _> = ProposalExpireTime::<T>::iter()
+				.map(|(block, proposal_id)| (proposal_id, block))
+				.collect();
+
+			let mut translated = 0u64;
+			Proposals::<T>::translate::<OldProposalOf<T>, _>(|proposal_id, old| {
+				translated.saturating_inc();
+				let deadline = deadlines.get(&proposal_id).copied().unwrap_or(now);
+				let proposal = Proposal {
+					owner: old.owner,
+					co_authors: Default::default(),
+					name: old.name,
+					description: old.description,
+					is_active: old.is_active,
+					voter_accounts: old.voter_accounts,
+					in_support: old.in_support,
+					in_oppose: old.in_oppose,
+					status: old.status,
+					deadline,
+					created_at: deadline.saturating_sub(longest),
+					extensions: Default::default(),
+					anti_sniping: None,
+					auto_extended: Zero::zero(),
+					candle_period: None,
+					tie_break: None,
+					owner_preference: None,
+					last_lead: Default::default(),
+					track: *track,
+					confirm_end: None,
+					curve_check: None,
+					revision: 0,
+				};
+
+				ProposalsByOwner::<T>::insert(&proposal.owner, proposal_id, ());
+				ProposalsByStatus::<T>::insert(&proposal.status, proposal_id, ());
+				ProposalsByCreation::<T>::insert(
+					Pallet::<T>::creation_key(proposal.created_at),
+					proposal_id,
+					(),
+				);
+				if proposal.is_active {
+					ActiveProposalCount::<T>::mutate(track, |active| {
+						*active = active.saturating_add(1)
+					});
+				}
+				Some(proposal)
+			});
+
+			let reads = translated
+				.saturating_add(deadlines.len() as u64)
+				.saturating_add(1);
+			T::DbWeight::get().reads_writes(reads, translated.saturating_mul(5))
+		}
+	}
+
+	/// Migrates the pallet from storage version 0 to 1.
+	pub type MigrateV0ToV1<T> = VersionedMigration<
+		0,
+		1,
+		InnerMigrateV0ToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
@@ -1,4 +1,7 @@
 use crate as pallet_proposal;
+use crate::electorate::ElectorateInfo;
+use crate::tracks::{TrackInfo, TracksInfo};
+use crate::types::{Curve, TieBreak};
 use frame_support::pallet_prelude::Hooks;
 use frame_support::{
 	derive_impl, parameter_types,
@@ -7,8 +10,8 @@ use frame_support::{
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
-	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	traits::{BadOrigin, BlakeTwo256, IdentityLookup},
+	BuildStorage, Perbill,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -58,12 +61,125 @@ impl system::Config for Test {
 
 impl pallet_insecure_randomness_collective_flip::Config for Test {}
 
+parameter_types! {
+	pub storage ElectorateMembers: Option<Vec<u64>> = None;
+	pub const DefaultTieBreak: TieBreak = TieBreak::Reject;
+	pub storage ApprovalCurve: Option<Curve> = None;
+	pub storage SupportCurve: Option<Curve> = None;
+	pub storage SecondingThreshold: u32 = 0;
+	pub storage LaunchPeriod: u64 = 100;
+	pub storage MinFastTrackPeriod: u64 = 10;
+}
+
+pub struct TestElectorate;
+impl ElectorateInfo<u64> for TestElectorate {
+	fn contains(who: &u64) -> bool {
+		ElectorateMembers::get().map_or(true, |members| members.contains(who))
+	}
+
+	fn size() -> Option<u32> {
+		ElectorateMembers::get().map(|members| members.len() as u32)
+	}
+}
+
+pub struct TestTracks;
+impl TracksInfo<u64, Balance> for TestTracks {
+	type Id = u16;
+	type RuntimeOrigin = RuntimeOrigin;
+
+	fn tracks() -> &'static [(Self::Id, TrackInfo<Balance>)] {
+		static TRACKS: [(u16, TrackInfo<Balance>); 3] = [
+			(
+				0,
+				TrackInfo {
+					name: "default",
+					submission_deposit: 10,
+					decision_deposit: 0,
+					min_duration: 1,
+					max_duration: 30,
+					min_turnout: Perbill::zero(),
+					approval_threshold: Perbill::from_percent(50),
+					max_active: 2,
+					confirm_period: 0,
+				},
+			),
+			(
+				1,
+				TrackInfo {
+					name: "treasury",
+					submission_deposit: 20,
+					decision_deposit: 0,
+					min_duration: 1,
+					max_duration: 7,
+					min_turnout: Perbill::from_percent(60),
+					approval_threshold: Perbill::from_percent(60),
+					max_active: 2,
+					confirm_period: 0,
+				},
+			),
+			(
+				2,
+				TrackInfo {
+					name: "referendum",
+					submission_deposit: 10,
+					decision_deposit: 30,
+					min_duration: 1,
+					max_duration: 30,
+					min_turnout: Perbill::zero(),
+					approval_threshold: Perbill::from_percent(50),
+					max_active: 2,
+					confirm_period: 10,
+				},
+			),
+		];
+		&TRACKS
+	}
+
+	fn authorize(id: Self::Id, origin: Self::RuntimeOrigin) -> Result<u64, BadOrigin> {
+		let who = frame_system::ensure_signed(origin)?;
+		match id {
+			// Only the first few accounts may open treasury proposals.
+			1 if who >= 4 => Err(BadOrigin),
+			_ => Ok(who),
+		}
+	}
+}
+
 impl pallet_proposal::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ProposalId = u32;
 	type NameLimit = ConstU32<20>;
 	type DescriptionLimit = ConstU32<100>;
 	type AccountLimit = ConstU32<3>;
+	type FastTrackOrigin = frame_system::EnsureRoot<u64>;
+	type MinFastTrackPeriod = MinFastTrackPeriod;
+	type ExtendOrigin = frame_system::EnsureRoot<u64>;
+	type MaxExtensions = ConstU32<2>;
+	type Randomness = RandomnessCollectiveFlip;
+	type Electorate = TestElectorate;
+	type TieBreak = DefaultTieBreak;
+	type ApprovalCurve = ApprovalCurve;
+	type SupportCurve = SupportCurve;
+	type SecondingThreshold = SecondingThreshold;
+	type MaxSeconds = ConstU32<3>;
+	type LaunchPeriod = LaunchPeriod;
+	type QueuePeriod = ConstU64<1_000>;
+	type MaxCoAuthors = ConstU32<2>;
+	type InvitationPeriod = ConstU64<500>;
+	type AmendmentPeriod = ConstU64<100>;
+	type MinAmendmentVotes = ConstU32<2>;
+	type MaxRevisions = ConstU32<2>;
+	type MaxPrerequisites = ConstU32<2>;
+	type MaxDependents = ConstU32<2>;
+	type MaxGroupSize = ConstU32<3>;
+	type MaxVotesPerAccount = ConstU32<2>;
+	type MaxCheckpoints = ConstU32<2>;
+	type ArchivePeriod = ConstU64<100>;
+	type MaxQueued = ConstU32<3>;
+	type MaxCancellations = ConstU32<2>;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Tracks = TestTracks;
 	type WeightInfo = ();
 }
 
@@ -87,16 +203,19 @@ impl pallet_balances::Config for Test {
 	type WeightInfo = ();
 	type FreezeIdentifier = ();
 	type MaxFreezes = ();
-	type RuntimeHoldReason = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = ();
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	let mut ext: sp_io::TestExternalities = system::GenesisConfig::<Test>::default()
+	let mut storage = system::GenesisConfig::<Test>::default()
 		.build_storage()
-		.unwrap()
-		.into();
+		.unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: (1..=10).map(|who| (who, 100)).collect() }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+	let mut ext: sp_io::TestExternalities = storage.into();
 	ext.execute_with(|| System::set_block_number(1));
 	ext
 }
@@ -1,8 +1,21 @@
-use crate::types::ProposalStatus;
-use crate::{mock::*, Error, Proposals, Vote};
+use crate::types::{
+	AntiSniping, Curve, NameMatch, Page, ProposalChange, ProposalFilter, ProposalQuery,
+	ProposalStatus, Tally, TieBreak,
+};
+use crate::weights::WeightInfo;
+use crate::{
+	mock::*, ActiveProposalCount, AmendmentEnd, Amendments, CandleCheckpoints, CoAuthorInvitations,
+	ConfirmationEnd, CurveCheck, Dependents, Error, Groups, PendingCancellations, Prerequisites,
+	ProposalExpireTime, ProposalGroup, Proposals, ProposalsByCreation, ProposalsByOwner,
+	ProposalsByStatus, Revisions, SecondingQueue, TallyCheckpoints, Vote, VotesByAccount,
+};
 use frame_support::pallet_prelude::ConstU32;
 use frame_support::BoundedVec;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResult};
+use sp_runtime::{
+	traits::{BadOrigin, BlakeTwo256, Hash},
+	Perbill,
+};
 
 fn create_proposal() {
 	let proposal_name: Vec<u8> = "First Proposal".into();
@@ -14,9 +27,16 @@ fn create_proposal() {
 
 	assert_ok!(Proposal::create_proposal(
 		RuntimeOrigin::signed(1),
+		0,
 		bounded_proposal_name,
 		bounded_proposal_description,
-		1
+		1,
+		None,
+		None,
+		None,
+		Default::default(),
+		Default::default(),
+		None
 	));
 
 	assert!(Proposals::<Test>::contains_key(1));
@@ -34,9 +54,16 @@ fn create_proposal_works() {
 
 		assert_ok!(Proposal::create_proposal(
 			RuntimeOrigin::signed(1),
+			0,
 			bounded_proposal_name,
 			bounded_proposal_description,
-			1
+			1,
+			None,
+			None,
+			None,
+			Default::default(),
+			Default::default(),
+			None
 		));
 
 		assert!(Proposals::<Test>::contains_key(1));
@@ -56,9 +83,16 @@ fn create_proposal_fails_invalid_proposal_duration() {
 		assert_noop!(
 			Proposal::create_proposal(
 				RuntimeOrigin::signed(1),
+				0,
 				bounded_proposal_name,
 				bounded_proposal_description,
-				0
+				0,
+				None,
+				None,
+				None,
+				Default::default(),
+				Default::default(),
+				None
 			),
 			Error::<Test>::InvalidProposalDuration
 		);
@@ -176,3 +210,2378 @@ fn proposal_rejected() {
 		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::Rejected);
 	});
 }
+
+#[test]
+fn fast_track_works() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+
+		assert_eq!(Proposals::<Test>::get(1).unwrap().deadline, 14_401);
+
+		assert_ok!(Proposal::fast_track(RuntimeOrigin::root(), 1, 100));
+
+		assert_eq!(Proposals::<Test>::get(1).unwrap().deadline, 101);
+		assert_eq!(ProposalExpireTime::<Test>::get(101), Some(1));
+		assert_eq!(ProposalExpireTime::<Test>::get(14_401), None);
+
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+
+		run_to_block(101);
+
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::Accepted);
+		System::assert_has_event(crate::Event::<Test>::ProposalFastTracked(1, 14_401, 101).into());
+	});
+}
+
+#[test]
+fn fast_track_fails_bad_origin() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+
+		assert_noop!(Proposal::fast_track(RuntimeOrigin::signed(1), 1, 100), BadOrigin);
+	});
+}
+
+#[test]
+fn fast_track_fails_period_too_short() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+
+		assert_noop!(
+			Proposal::fast_track(RuntimeOrigin::root(), 1, 9),
+			Error::<Test>::FastTrackPeriodTooShort
+		);
+
+		// The new deadline is at least the next block, whatever the minimum period.
+		MinFastTrackPeriod::set(&0);
+		assert_noop!(
+			Proposal::fast_track(RuntimeOrigin::root(), 1, 0),
+			Error::<Test>::FastTrackPeriodTooShort
+		);
+		assert_ok!(Proposal::fast_track(RuntimeOrigin::root(), 1, 1));
+		assert_eq!(Proposals::<Test>::get(1).unwrap().deadline, 2);
+	});
+}
+
+#[test]
+fn fast_track_fails_deadline_not_shortened() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+
+		assert_noop!(
+			Proposal::fast_track(RuntimeOrigin::root(), 1, 14_400),
+			Error::<Test>::DeadlineNotShortened
+		);
+	});
+}
+
+#[test]
+fn fast_track_fails_proposal_not_active() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+
+		run_to_block(15_000);
+		assert_noop!(
+			Proposal::fast_track(RuntimeOrigin::root(), 1, 100),
+			Error::<Test>::ProposalNotActive
+		);
+	});
+}
+
+#[test]
+fn extend_deadline_works() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+
+		assert_ok!(Proposal::extend_deadline(RuntimeOrigin::signed(1), 1, 100));
+
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert_eq!(proposal.deadline, 14_501);
+		assert_eq!(proposal.extensions.len(), 1);
+		assert!(proposal.extensions[0].by_owner);
+		assert_eq!(ProposalExpireTime::<Test>::get(14_501), Some(1));
+		assert_eq!(ProposalExpireTime::<Test>::get(14_401), None);
+		System::assert_has_event(
+			crate::Event::<Test>::ProposalDeadlineExtended(1, 14_401, 14_501).into(),
+		);
+
+		assert_ok!(Proposal::extend_deadline(RuntimeOrigin::root(), 1, 100));
+		assert_eq!(Proposals::<Test>::get(1).unwrap().deadline, 14_601);
+
+		run_to_block(14_401);
+		assert!(Proposals::<Test>::get(1).unwrap().is_active);
+
+		run_to_block(14_601);
+		assert!(!Proposals::<Test>::get(1).unwrap().is_active);
+	});
+}
+
+#[test]
+fn extend_deadline_fails_owner_extension_used() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+
+		assert_ok!(Proposal::extend_deadline(RuntimeOrigin::signed(1), 1, 100));
+
+		assert_noop!(
+			Proposal::extend_deadline(RuntimeOrigin::signed(1), 1, 100),
+			Error::<Test>::OwnerExtensionUsed
+		);
+	});
+}
+
+#[test]
+fn extend_deadline_fails_not_owner() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+
+		assert_noop!(
+			Proposal::extend_deadline(RuntimeOrigin::signed(2), 1, 100),
+			Error::<Test>::NotProposalOwner
+		);
+	});
+}
+
+#[test]
+fn extend_deadline_fails_beyond_limit() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+
+		// Created at block 1, so the deadline can go up to block 1 + 30 days.
+		assert_ok!(Proposal::extend_deadline(RuntimeOrigin::root(), 1, 29 * 14_400));
+
+		assert_noop!(
+			Proposal::extend_deadline(RuntimeOrigin::root(), 1, 1),
+			Error::<Test>::ExtensionBeyondLimit
+		);
+	});
+}
+
+#[test]
+fn extend_deadline_fails_too_many_extensions() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+
+		assert_ok!(Proposal::extend_deadline(RuntimeOrigin::root(), 1, 100));
+		assert_ok!(Proposal::extend_deadline(RuntimeOrigin::root(), 1, 100));
+
+		assert_noop!(
+			Proposal::extend_deadline(RuntimeOrigin::root(), 1, 100),
+			Error::<Test>::TooManyExtensions
+		);
+	});
+}
+
+fn create_proposal_with_anti_sniping(anti_sniping: AntiSniping<u64>) {
+	let proposal_name: Vec<u8> = "First Proposal".into();
+	let bounded_proposal_name: BoundedVec<u8, ConstU32<20>> = proposal_name.try_into().unwrap();
+
+	let proposal_description: Vec<u8> = "Description of first proposal test".into();
+	let bounded_proposal_description: BoundedVec<u8, ConstU32<100>> =
+		proposal_description.try_into().unwrap();
+
+	assert_ok!(Proposal::create_proposal(
+		RuntimeOrigin::signed(1),
+		0,
+		bounded_proposal_name,
+		bounded_proposal_description,
+		1,
+		Some(anti_sniping),
+		None,
+		None,
+		Default::default(),
+		Default::default(),
+		None
+	));
+}
+
+#[test]
+fn anti_sniping_extends_deadline_on_lead_change() {
+	new_test_ext().execute_with(|| {
+		create_proposal_with_anti_sniping(AntiSniping {
+			window: 100,
+			extension: 50,
+			max_extension: 80,
+		});
+
+		// Inside the window, a vote taking the lead pushes the deadline back.
+		run_to_block(14_350);
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert_eq!(Proposals::<Test>::get(1).unwrap().deadline, 14_451);
+		assert_eq!(ProposalExpireTime::<Test>::get(14_451), Some(1));
+		assert_eq!(ProposalExpireTime::<Test>::get(14_401), None);
+		System::assert_has_event(
+			crate::Event::<Test>::ProposalAutoExtended(1, 14_401, 14_451).into(),
+		);
+
+		// Only the rest of `max_extension` is granted.
+		run_to_block(14_440);
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(3), 1, Vote::NO));
+		assert_eq!(Proposals::<Test>::get(1).unwrap().deadline, 14_481);
+
+		// The cap is reached, so later lead changes do not move the deadline.
+		run_to_block(14_470);
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(4), 1, Vote::NO));
+		assert_eq!(Proposals::<Test>::get(1).unwrap().deadline, 14_481);
+
+		run_to_block(14_481);
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::Rejected);
+	});
+}
+
+#[test]
+fn anti_sniping_ignores_votes_outside_window() {
+	new_test_ext().execute_with(|| {
+		create_proposal_with_anti_sniping(AntiSniping {
+			window: 100,
+			extension: 50,
+			max_extension: 80,
+		});
+
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert_eq!(Proposals::<Test>::get(1).unwrap().deadline, 14_401);
+
+		// Inside the window, but the leading side does not change.
+		run_to_block(14_350);
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(3), 1, Vote::YES));
+		assert_eq!(Proposals::<Test>::get(1).unwrap().deadline, 14_401);
+	});
+}
+
+#[test]
+fn create_proposal_fails_invalid_anti_sniping() {
+	new_test_ext().execute_with(|| {
+		let proposal_name: Vec<u8> = "First Proposal".into();
+		let bounded_proposal_name: BoundedVec<u8, ConstU32<20>> = proposal_name.try_into().unwrap();
+
+		let proposal_description: Vec<u8> = "Description of first proposal test".into();
+		let bounded_proposal_description: BoundedVec<u8, ConstU32<100>> =
+			proposal_description.try_into().unwrap();
+
+		assert_noop!(
+			Proposal::create_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				bounded_proposal_name,
+				bounded_proposal_description,
+				1,
+				Some(AntiSniping { window: 0, extension: 50, max_extension: 80 }),
+				None,
+				None,
+				Default::default(),
+				Default::default(),
+				None
+			),
+			Error::<Test>::InvalidAntiSniping
+		);
+	});
+}
+
+#[test]
+fn create_proposal_fails_anti_sniping_beyond_track_limit() {
+	new_test_ext().execute_with(|| {
+		// Track 1 runs proposals for at most 7 days.
+		let max_extension = 14_400 * 6 + 1;
+		assert_noop!(
+			Proposal::create_proposal(
+				RuntimeOrigin::signed(1),
+				1,
+				"First Proposal".as_bytes().to_vec().try_into().unwrap(),
+				"Description of first proposal test"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				1,
+				Some(AntiSniping { window: 100, extension: 50, max_extension }),
+				None,
+				None,
+				Default::default(),
+				Default::default(),
+				None
+			),
+			Error::<Test>::InvalidAntiSniping
+		);
+	});
+}
+
+fn create_candle_proposal(candle_period: u64) {
+	let proposal_name: Vec<u8> = "First Proposal".into();
+	let bounded_proposal_name: BoundedVec<u8, ConstU32<20>> = proposal_name.try_into().unwrap();
+
+	let proposal_description: Vec<u8> = "Description of first proposal test".into();
+	let bounded_proposal_description: BoundedVec<u8, ConstU32<100>> =
+		proposal_description.try_into().unwrap();
+
+	assert_ok!(Proposal::create_proposal(
+		RuntimeOrigin::signed(1),
+		0,
+		bounded_proposal_name,
+		bounded_proposal_description,
+		1,
+		None,
+		Some(candle_period),
+		None,
+		Default::default(),
+		Default::default(),
+		None
+	));
+}
+
+#[test]
+fn candle_ending_uses_tally_at_drawn_block() {
+	new_test_ext().execute_with(|| {
+		create_candle_proposal(100);
+
+		// Before the ending period, votes are not checkpointed.
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert_eq!(CandleCheckpoints::<Test>::iter_prefix(1).count(), 0);
+
+		run_to_block(14_350);
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(3), 1, Vote::NO));
+		assert_eq!(CandleCheckpoints::<Test>::get(1, 14_301), Some((1, 0)));
+		assert_eq!(CandleCheckpoints::<Test>::get(1, 14_350), Some((1, 1)));
+
+		run_to_block(14_390);
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(4), 1, Vote::NO));
+		assert_eq!(CandleCheckpoints::<Test>::get(1, 14_390), Some((1, 2)));
+
+		run_to_block(14_401);
+
+		let candle_block = System::events()
+			.into_iter()
+			.find_map(|record| match record.event {
+				RuntimeEvent::Proposal(crate::Event::CandleEnded(1, block)) => Some(block),
+				_ => None,
+			})
+			.unwrap();
+		assert!((14_301..14_401).contains(&candle_block));
+
+		let expected =
+			if candle_block < 14_350 { ProposalStatus::Accepted } else { ProposalStatus::Rejected };
+		assert!(Proposals::<Test>::get(1).unwrap().status == expected);
+		assert_eq!(CandleCheckpoints::<Test>::iter_prefix(1).count(), 0);
+	});
+}
+
+#[test]
+fn candle_ending_without_late_votes_uses_final_tally() {
+	new_test_ext().execute_with(|| {
+		create_candle_proposal(100);
+
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+
+		run_to_block(14_401);
+
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::Accepted);
+	});
+}
+
+#[test]
+fn create_proposal_fails_incompatible_ending() {
+	new_test_ext().execute_with(|| {
+		let proposal_name: Vec<u8> = "First Proposal".into();
+		let bounded_proposal_name: BoundedVec<u8, ConstU32<20>> = proposal_name.try_into().unwrap();
+
+		let proposal_description: Vec<u8> = "Description of first proposal test".into();
+		let bounded_proposal_description: BoundedVec<u8, ConstU32<100>> =
+			proposal_description.try_into().unwrap();
+
+		assert_noop!(
+			Proposal::create_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				bounded_proposal_name.clone(),
+				bounded_proposal_description.clone(),
+				1,
+				Some(AntiSniping { window: 100, extension: 50, max_extension: 80 }),
+				Some(100),
+				None,
+				Default::default(),
+				Default::default(),
+				None
+			),
+			Error::<Test>::IncompatibleEnding
+		);
+
+		assert_noop!(
+			Proposal::create_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				bounded_proposal_name,
+				bounded_proposal_description,
+				1,
+				None,
+				Some(14_401),
+				None,
+				Default::default(),
+				Default::default(),
+				None
+			),
+			Error::<Test>::InvalidCandlePeriod
+		);
+	});
+}
+
+#[test]
+fn proposal_decided_early_accepted() {
+	new_test_ext().execute_with(|| {
+		ElectorateMembers::set(&Some(vec![1, 2, 3, 4]));
+		create_proposal();
+
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert!(Proposals::<Test>::get(1).unwrap().is_active);
+
+		// One voter is left, who cannot flip a 2-0 lead.
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(3), 1, Vote::YES));
+
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert!(!proposal.is_active);
+		assert!(proposal.status == ProposalStatus::Accepted);
+		assert_eq!(ProposalExpireTime::<Test>::get(14_401), None);
+		System::assert_has_event(
+			crate::Event::<Test>::ProposalDecidedEarly(1, ProposalStatus::Accepted, false).into(),
+		);
+		System::assert_has_event(
+			crate::Event::<Test>::ProposalClosed(
+				1,
+				ProposalStatus::Accepted,
+				false,
+				Proposal::voter_root(1).unwrap(),
+			)
+			.into(),
+		);
+
+		assert_noop!(
+			Proposal::vote(RuntimeOrigin::signed(4), 1, Vote::NO),
+			Error::<Test>::ProposalNotActive
+		);
+	});
+}
+
+#[test]
+fn vote_refunds_weight_unless_it_closes() {
+	new_test_ext().execute_with(|| {
+		ElectorateMembers::set(&Some(vec![1, 2, 3, 4]));
+		create_proposal();
+
+		let info = Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES).unwrap();
+		assert_eq!(info.actual_weight, Some(<() as WeightInfo>::vote()));
+
+		let info = Proposal::vote(RuntimeOrigin::signed(3), 1, Vote::YES).unwrap();
+		assert_eq!(
+			info.actual_weight,
+			Some(<() as WeightInfo>::vote().saturating_add(<() as WeightInfo>::close_proposal(2)))
+		);
+	});
+}
+
+#[test]
+fn proposal_decided_early_rejected() {
+	new_test_ext().execute_with(|| {
+		ElectorateMembers::set(&Some(vec![1, 2, 3]));
+		create_proposal();
+
+		// Every eligible account has voted and the result is a tie.
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(3), 1, Vote::NO));
+
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert!(!proposal.is_active);
+		assert!(proposal.status == ProposalStatus::Rejected);
+	});
+}
+
+#[test]
+fn proposal_not_decided_early_while_outcome_open() {
+	new_test_ext().execute_with(|| {
+		ElectorateMembers::set(&Some(vec![1, 2, 3, 4]));
+		create_proposal();
+
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(3), 1, Vote::NO));
+
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert!(proposal.is_active);
+		assert_eq!(ProposalExpireTime::<Test>::get(14_401), Some(1));
+	});
+}
+
+#[test]
+fn vote_outside_electorate_fails() {
+	new_test_ext().execute_with(|| {
+		ElectorateMembers::set(&Some(vec![1, 2, 3]));
+		create_proposal();
+
+		assert_noop!(
+			Proposal::vote(RuntimeOrigin::signed(4), 1, Vote::YES),
+			Error::<Test>::NotInElectorate
+		);
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+	});
+}
+
+fn create_proposal_with_tie_break(tie_break: TieBreak) {
+	let proposal_name: Vec<u8> = "First Proposal".into();
+	let bounded_proposal_name: BoundedVec<u8, ConstU32<20>> = proposal_name.try_into().unwrap();
+
+	let proposal_description: Vec<u8> = "Description of first proposal test".into();
+	let bounded_proposal_description: BoundedVec<u8, ConstU32<100>> =
+		proposal_description.try_into().unwrap();
+
+	assert_ok!(Proposal::create_proposal(
+		RuntimeOrigin::signed(1),
+		0,
+		bounded_proposal_name,
+		bounded_proposal_description,
+		1,
+		None,
+		None,
+		Some(tie_break),
+		Default::default(),
+		Default::default(),
+		None
+	));
+}
+
+fn tie_and_close() {
+	assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+	assert_ok!(Proposal::vote(RuntimeOrigin::signed(3), 1, Vote::NO));
+
+	run_to_block(14_401);
+}
+
+#[test]
+fn tie_rejected_by_default() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		tie_and_close();
+
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::Rejected);
+		System::assert_has_event(
+			crate::Event::<Test>::ProposalClosed(
+				1,
+				ProposalStatus::Rejected,
+				true,
+				Proposal::voter_root(1).unwrap(),
+			)
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn tie_accepted_with_accept_policy() {
+	new_test_ext().execute_with(|| {
+		create_proposal_with_tie_break(TieBreak::Accept);
+		tie_and_close();
+
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::Accepted);
+		System::assert_has_event(
+			crate::Event::<Test>::ProposalClosed(
+				1,
+				ProposalStatus::Accepted,
+				true,
+				Proposal::voter_root(1).unwrap(),
+			)
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn tie_follows_status_quo() {
+	new_test_ext().execute_with(|| {
+		create_proposal_with_tie_break(TieBreak::StatusQuo);
+
+		// Support led before the opposition drew level.
+		tie_and_close();
+
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::Accepted);
+	});
+}
+
+#[test]
+fn tie_follows_owner_preference() {
+	new_test_ext().execute_with(|| {
+		create_proposal_with_tie_break(TieBreak::OwnerPreference);
+
+		assert_noop!(
+			Proposal::set_tie_preference(RuntimeOrigin::signed(2), 1, Vote::YES),
+			Error::<Test>::NotProposalOwner
+		);
+		assert_ok!(Proposal::set_tie_preference(RuntimeOrigin::signed(1), 1, Vote::YES));
+
+		tie_and_close();
+
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::Accepted);
+	});
+}
+
+#[test]
+fn tie_drawn_at_random_is_marked_tied() {
+	new_test_ext().execute_with(|| {
+		create_proposal_with_tie_break(TieBreak::Random);
+		tie_and_close();
+
+		let status = Proposals::<Test>::get(1).unwrap().status;
+		System::assert_has_event(
+			crate::Event::<Test>::ProposalClosed(1, status, true, Proposal::voter_root(1).unwrap())
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn proposal_closed_not_tied() {
+	new_test_ext().execute_with(|| {
+		create_proposal_with_tie_break(TieBreak::Accept);
+
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::NO));
+
+		run_to_block(14_401);
+
+		System::assert_has_event(
+			crate::Event::<Test>::ProposalClosed(
+				1,
+				ProposalStatus::Rejected,
+				false,
+				Proposal::voter_root(1).unwrap(),
+			)
+			.into(),
+		);
+	});
+}
+
+fn create_proposal_on_track(who: u64, track: u16, duration: u32) -> DispatchResult {
+	let proposal_name: Vec<u8> = "Track Proposal".into();
+	let bounded_proposal_name: BoundedVec<u8, ConstU32<20>> = proposal_name.try_into().unwrap();
+
+	let proposal_description: Vec<u8> = "Description of track proposal test".into();
+	let bounded_proposal_description: BoundedVec<u8, ConstU32<100>> =
+		proposal_description.try_into().unwrap();
+
+	Proposal::create_proposal(
+		RuntimeOrigin::signed(who),
+		track,
+		bounded_proposal_name,
+		bounded_proposal_description,
+		duration,
+		None,
+		None,
+		None,
+		Default::default(),
+		Default::default(),
+		None,
+	)
+}
+
+#[test]
+fn track_submission_deposit_held_until_close() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_proposal_on_track(1, 1, 1));
+
+		assert_eq!(Balances::reserved_balance(1), 20);
+		assert_eq!(ActiveProposalCount::<Test>::get(1), 1);
+
+		run_to_block(14_401);
+
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(ActiveProposalCount::<Test>::get(1), 0);
+	});
+}
+
+#[test]
+fn create_proposal_fails_bad_track() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(create_proposal_on_track(1, 7, 1), Error::<Test>::BadTrack);
+	});
+}
+
+#[test]
+fn create_proposal_fails_unauthorized_track() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(create_proposal_on_track(5, 1, 1), BadOrigin);
+		assert_ok!(create_proposal_on_track(5, 0, 1));
+	});
+}
+
+#[test]
+fn create_proposal_fails_duration_outside_track() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(create_proposal_on_track(1, 1, 8), Error::<Test>::InvalidProposalDuration);
+		assert_ok!(create_proposal_on_track(1, 0, 8));
+	});
+}
+
+#[test]
+fn create_proposal_fails_track_full() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_proposal_on_track(1, 0, 1));
+		assert_ok!(create_proposal_on_track(2, 0, 1));
+
+		assert_noop!(create_proposal_on_track(3, 0, 1), Error::<Test>::TrackFull);
+		assert_ok!(create_proposal_on_track(3, 1, 1));
+	});
+}
+
+#[test]
+fn track_turnout_below_minimum_rejects() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_proposal_on_track(1, 1, 1));
+
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+
+		run_to_block(14_401);
+
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::Rejected);
+	});
+}
+
+#[test]
+fn track_approval_threshold_applies() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_proposal_on_track(1, 1, 1));
+		assert_ok!(create_proposal_on_track(1, 1, 1));
+
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(3), 1, Vote::YES));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 2, Vote::YES));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(3), 2, Vote::NO));
+
+		run_to_block(14_402);
+
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::Accepted);
+		assert!(Proposals::<Test>::get(2).unwrap().status == ProposalStatus::Rejected);
+	});
+}
+
+#[test]
+fn proposal_prepares_until_decision_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_proposal_on_track(1, 2, 1));
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::Preparing);
+
+		assert_ok!(Proposal::place_decision_deposit(RuntimeOrigin::signed(3), 1));
+
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::VotingInProgress);
+		assert_eq!(Balances::reserved_balance(3), 30);
+		System::assert_last_event(crate::Event::<Test>::DecisionDepositPlaced(1, 3, 30).into());
+
+		assert_noop!(
+			Proposal::place_decision_deposit(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::NotPreparing
+		);
+	});
+}
+
+#[test]
+fn place_decision_deposit_fails_not_preparing() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+
+		assert_noop!(
+			Proposal::place_decision_deposit(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::NotPreparing
+		);
+	});
+}
+
+#[test]
+fn preparing_proposal_rejected_at_deadline() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_proposal_on_track(1, 2, 1));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+
+		// Passing, but never confirming without a decision deposit.
+		run_to_block(20);
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::Preparing);
+
+		run_to_block(14_401);
+
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::Rejected);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn passing_proposal_accepted_after_confirmation_period() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_proposal_on_track(1, 2, 1));
+		assert_ok!(Proposal::place_decision_deposit(RuntimeOrigin::signed(3), 1));
+
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert!(proposal.status == ProposalStatus::Confirming);
+		assert_eq!(proposal.confirm_end, Some(11));
+		System::assert_has_event(crate::Event::<Test>::ConfirmStarted(1, 11).into());
+
+		run_to_block(11);
+
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert!(proposal.status == ProposalStatus::Accepted);
+		assert!(!proposal.is_active);
+		assert!(ProposalExpireTime::<Test>::get(14_401).is_none());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		System::assert_has_event(crate::Event::<Test>::ProposalConfirmed(1).into());
+	});
+}
+
+#[test]
+fn confirmation_waits_for_free_end_slot() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_proposal_on_track(1, 2, 1));
+		assert_ok!(Proposal::place_decision_deposit(RuntimeOrigin::signed(3), 1));
+		for block in 11..75 {
+			ConfirmationEnd::<Test>::insert(block, 2);
+		}
+
+		// No block is free to end the confirmation on, so the proposal keeps deciding.
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert!(proposal.status == ProposalStatus::VotingInProgress);
+		assert_eq!(proposal.confirm_end, None);
+
+		ConfirmationEnd::<Test>::remove(74);
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(4), 1, Vote::YES));
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert!(proposal.status == ProposalStatus::Confirming);
+		assert_eq!(proposal.confirm_end, Some(74));
+	});
+}
+
+#[test]
+fn confirmation_aborted_when_proposal_stops_passing() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_proposal_on_track(1, 2, 1));
+		assert_ok!(Proposal::place_decision_deposit(RuntimeOrigin::signed(4), 1));
+
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		run_to_block(5);
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(3), 1, Vote::NO));
+
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert!(proposal.status == ProposalStatus::VotingInProgress);
+		assert_eq!(proposal.confirm_end, None);
+		assert!(ConfirmationEnd::<Test>::get(11).is_none());
+		System::assert_has_event(crate::Event::<Test>::ConfirmAborted(1).into());
+
+		run_to_block(20);
+
+		assert!(Proposals::<Test>::get(1).unwrap().is_active);
+	});
+}
+
+#[test]
+fn curve_threshold_decays() {
+	let linear = Curve::LinearDecreasing {
+		begin: Perbill::from_percent(100),
+		end: Perbill::from_percent(50),
+	};
+	assert_eq!(linear.threshold(Perbill::zero()), Perbill::from_percent(100));
+	assert_eq!(linear.threshold(Perbill::from_percent(50)), Perbill::from_percent(75));
+	assert_eq!(linear.threshold(Perbill::one()), Perbill::from_percent(50));
+
+	let reciprocal = Curve::Reciprocal {
+		begin: Perbill::from_percent(100),
+		end: Perbill::from_percent(50),
+		factor: 1,
+	};
+	assert_eq!(reciprocal.threshold(Perbill::zero()), Perbill::from_percent(100));
+	assert!(reciprocal.threshold(Perbill::from_percent(50)) < Perbill::from_percent(75));
+	assert!(reciprocal.threshold(Perbill::from_percent(50)) > Perbill::from_percent(50));
+	assert_eq!(reciprocal.threshold(Perbill::one()), Perbill::from_percent(50));
+}
+
+#[test]
+fn proposal_passes_once_tally_beats_approval_curve() {
+	new_test_ext().execute_with(|| {
+		ApprovalCurve::set(&Some(Curve::LinearDecreasing {
+			begin: Perbill::from_percent(100),
+			end: Perbill::from_percent(50),
+		}));
+		create_proposal();
+
+		// Full support only ties the curve at the start.
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert!(proposal.is_active);
+		assert_eq!(proposal.curve_check, Some(2));
+
+		run_to_block(2);
+
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert!(proposal.status == ProposalStatus::Accepted);
+		assert!(!proposal.is_active);
+		assert!(ProposalExpireTime::<Test>::get(14_401).is_none());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		System::assert_has_event(crate::Event::<Test>::ProposalPassedEarly(1).into());
+	});
+}
+
+#[test]
+fn proposal_passes_once_turnout_beats_support_curve() {
+	new_test_ext().execute_with(|| {
+		SupportCurve::set(&Some(Curve::LinearDecreasing {
+			begin: Perbill::from_percent(100),
+			end: Perbill::zero(),
+		}));
+		create_proposal();
+
+		// One of three eligible voters turns out, beating the curve two thirds of the way in.
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		let check = Proposals::<Test>::get(1).unwrap().curve_check.unwrap();
+		assert!((9_600..=9_602).contains(&check));
+
+		run_to_block(check - 1);
+		assert!(Proposals::<Test>::get(1).unwrap().is_active);
+
+		run_to_block(check);
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::Accepted);
+	});
+}
+
+#[test]
+fn curve_check_waits_for_free_slot() {
+	new_test_ext().execute_with(|| {
+		ApprovalCurve::set(&Some(Curve::LinearDecreasing {
+			begin: Perbill::from_percent(100),
+			end: Perbill::from_percent(50),
+		}));
+		create_proposal();
+		for block in 2..66 {
+			CurveCheck::<Test>::insert(block, 2);
+		}
+
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert_eq!(Proposals::<Test>::get(1).unwrap().curve_check, None);
+
+		CurveCheck::<Test>::remove(65);
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(3), 1, Vote::YES));
+		assert_eq!(Proposals::<Test>::get(1).unwrap().curve_check, Some(65));
+		assert_eq!(CurveCheck::<Test>::get(65), Some(1));
+	});
+}
+
+#[test]
+fn curve_check_dropped_when_tally_stops_beating_curve() {
+	new_test_ext().execute_with(|| {
+		ApprovalCurve::set(&Some(Curve::LinearDecreasing {
+			begin: Perbill::from_percent(100),
+			end: Perbill::from_percent(50),
+		}));
+		create_proposal();
+
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(3), 1, Vote::NO));
+
+		assert_eq!(Proposals::<Test>::get(1).unwrap().curve_check, None);
+		assert!(CurveCheck::<Test>::get(2).is_none());
+
+		// Half support only ties the curve at the deadline.
+		run_to_block(14_401);
+		System::assert_has_event(
+			crate::Event::<Test>::ProposalClosed(
+				1,
+				ProposalStatus::Rejected,
+				true,
+				Proposal::voter_root(1).unwrap(),
+			)
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn proposal_queued_until_seconding_threshold() {
+	new_test_ext().execute_with(|| {
+		SecondingThreshold::set(&2);
+		create_proposal();
+
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert!(proposal.status == ProposalStatus::Queued);
+		assert!(!proposal.is_active);
+		assert_noop!(
+			Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES),
+			Error::<Test>::ProposalNotActive
+		);
+
+		assert_ok!(Proposal::second(RuntimeOrigin::signed(2), 1, Some(5)));
+		assert_eq!(Balances::reserved_balance(2), 5);
+		assert!(SecondingQueue::<Test>::contains_key(1));
+
+		run_to_block(50);
+		assert_ok!(Proposal::second(RuntimeOrigin::signed(3), 1, None));
+
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert!(proposal.status == ProposalStatus::VotingInProgress);
+		assert!(proposal.is_active);
+		assert_eq!(proposal.created_at, 50);
+		assert_eq!(proposal.deadline, 14_450);
+		assert!(!SecondingQueue::<Test>::contains_key(1));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		System::assert_last_event(crate::Event::<Test>::ProposalLaunched(1, 14_450).into());
+
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+	});
+}
+
+#[test]
+fn second_fails() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		SecondingThreshold::set(&3);
+		assert_ok!(create_proposal_on_track(1, 0, 1));
+
+		assert_noop!(Proposal::second(RuntimeOrigin::signed(2), 1, None), Error::<Test>::NotQueued);
+		assert_noop!(
+			Proposal::second(RuntimeOrigin::signed(1), 2, None),
+			Error::<Test>::OwnerCannotSecond
+		);
+
+		assert_ok!(Proposal::second(RuntimeOrigin::signed(2), 2, None));
+		assert_noop!(
+			Proposal::second(RuntimeOrigin::signed(2), 2, None),
+			Error::<Test>::AlreadySeconded
+		);
+	});
+}
+
+#[test]
+fn most_seconded_proposal_launched_each_launch_period() {
+	new_test_ext().execute_with(|| {
+		SecondingThreshold::set(&3);
+		assert_ok!(create_proposal_on_track(1, 0, 1));
+		assert_ok!(create_proposal_on_track(1, 0, 1));
+
+		assert_ok!(Proposal::second(RuntimeOrigin::signed(2), 1, Some(5)));
+		assert_ok!(Proposal::second(RuntimeOrigin::signed(2), 2, None));
+		assert_ok!(Proposal::second(RuntimeOrigin::signed(3), 2, None));
+
+		run_to_block(100);
+
+		assert!(Proposals::<Test>::get(2).unwrap().is_active);
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::Queued);
+		System::assert_has_event(crate::Event::<Test>::ProposalLaunched(2, 14_500).into());
+
+		run_to_block(200);
+
+		assert!(Proposals::<Test>::get(1).unwrap().is_active);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn queue_ranked_by_seconds_then_backing() {
+	new_test_ext().execute_with(|| {
+		SecondingThreshold::set(&3);
+		assert_ok!(create_proposal_on_track(1, 0, 1));
+		assert_ok!(create_proposal_on_track(1, 0, 1));
+		assert_ok!(create_proposal_on_track(1, 2, 1));
+		let ranked = || {
+			Proposal::queue_ranking()
+				.iter()
+				.map(|rank| rank.0)
+				.collect::<Vec<_>>()
+		};
+
+		assert_ok!(Proposal::second(RuntimeOrigin::signed(2), 1, Some(5)));
+		assert_ok!(Proposal::second(RuntimeOrigin::signed(2), 2, None));
+		assert_ok!(Proposal::second(RuntimeOrigin::signed(3), 2, None));
+		assert_ok!(Proposal::second(RuntimeOrigin::signed(2), 3, Some(5)));
+		assert_eq!(ranked(), vec![2, 1, 3]);
+
+		assert_ok!(Proposal::second(RuntimeOrigin::signed(3), 3, Some(10)));
+		assert_eq!(ranked(), vec![3, 2, 1]);
+		assert_eq!(Proposal::queue_ranking()[0], (3, 2, 15, 1));
+
+		run_to_block(100);
+		System::assert_has_event(crate::Event::<Test>::ProposalLaunched(3, 14_500).into());
+		assert_eq!(ranked(), vec![2, 1]);
+	});
+}
+
+#[test]
+fn queue_full_fails() {
+	new_test_ext().execute_with(|| {
+		SecondingThreshold::set(&3);
+		assert_ok!(create_proposal_on_track(1, 0, 1));
+		assert_ok!(create_proposal_on_track(1, 0, 1));
+		assert_ok!(create_proposal_on_track(1, 2, 1));
+		assert_noop!(create_proposal_on_track(1, 2, 1), Error::<Test>::QueueFull);
+	});
+}
+
+#[test]
+fn queued_proposal_expires_from_queue() {
+	new_test_ext().execute_with(|| {
+		SecondingThreshold::set(&2);
+		LaunchPeriod::set(&0);
+		create_proposal();
+
+		assert_ok!(Proposal::second(RuntimeOrigin::signed(2), 1, Some(5)));
+
+		run_to_block(1_001);
+
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert!(proposal.status == ProposalStatus::Rejected);
+		assert!(!SecondingQueue::<Test>::contains_key(1));
+		assert!(Proposal::queue_ranking().is_empty());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		System::assert_has_event(
+			crate::Event::<Test>::ProposalClosed(
+				1,
+				ProposalStatus::Rejected,
+				false,
+				Proposal::voter_root(1).unwrap(),
+			)
+			.into(),
+		);
+	});
+}
+
+fn create_co_authored_proposal(who: u64, co_authors: Vec<u64>) -> DispatchResult {
+	let proposal_name: Vec<u8> = "Joint Proposal".into();
+	let bounded_proposal_name: BoundedVec<u8, ConstU32<20>> = proposal_name.try_into().unwrap();
+
+	let proposal_description: Vec<u8> = "Description of joint proposal test".into();
+	let bounded_proposal_description: BoundedVec<u8, ConstU32<100>> =
+		proposal_description.try_into().unwrap();
+
+	Proposal::create_proposal(
+		RuntimeOrigin::signed(who),
+		0,
+		bounded_proposal_name,
+		bounded_proposal_description,
+		1,
+		None,
+		None,
+		None,
+		co_authors.try_into().unwrap(),
+		Default::default(),
+		None,
+	)
+}
+
+#[test]
+fn co_authored_proposal_opens_once_all_accept() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_co_authored_proposal(1, vec![2, 3]));
+
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert!(proposal.status == ProposalStatus::AwaitingCoAuthors);
+		assert!(!proposal.is_active);
+		assert_noop!(
+			Proposal::vote(RuntimeOrigin::signed(4), 1, Vote::YES),
+			Error::<Test>::ProposalNotActive
+		);
+
+		assert_ok!(Proposal::accept_co_authorship(RuntimeOrigin::signed(2), 1));
+		System::assert_last_event(crate::Event::<Test>::CoAuthorAccepted(1, 2).into());
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::AwaitingCoAuthors);
+
+		run_to_block(10);
+		assert_ok!(Proposal::accept_co_authorship(RuntimeOrigin::signed(3), 1));
+
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert!(proposal.status == ProposalStatus::VotingInProgress);
+		assert!(proposal.is_active);
+		assert_eq!(proposal.co_authors.to_vec(), vec![2, 3]);
+		assert_eq!(proposal.created_at, 10);
+		assert_eq!(proposal.deadline, 14_410);
+		assert!(!CoAuthorInvitations::<Test>::contains_key(1));
+		System::assert_last_event(crate::Event::<Test>::ProposalLaunched(1, 14_410).into());
+	});
+}
+
+#[test]
+fn co_authors_share_owner_rights() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_co_authored_proposal(1, vec![2]));
+		assert_ok!(Proposal::accept_co_authorship(RuntimeOrigin::signed(2), 1));
+
+		assert_noop!(
+			Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES),
+			Error::<Test>::OwnerCannotVote
+		);
+		assert_ok!(Proposal::set_tie_preference(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert_ok!(Proposal::extend_deadline(RuntimeOrigin::signed(2), 1, 100));
+
+		// The owner extension is shared between the authors.
+		assert_noop!(
+			Proposal::extend_deadline(RuntimeOrigin::signed(1), 1, 100),
+			Error::<Test>::OwnerExtensionUsed
+		);
+	});
+}
+
+#[test]
+fn co_authorship_fails() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(create_co_authored_proposal(1, vec![1]), Error::<Test>::InvalidCoAuthors);
+		assert_noop!(create_co_authored_proposal(1, vec![2, 2]), Error::<Test>::InvalidCoAuthors);
+
+		assert_ok!(create_co_authored_proposal(1, vec![2]));
+		assert_noop!(
+			Proposal::accept_co_authorship(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::NotInvited
+		);
+		assert_noop!(
+			Proposal::accept_co_authorship(RuntimeOrigin::signed(2), 2),
+			Error::<Test>::NotInvited
+		);
+	});
+}
+
+#[test]
+fn co_author_invitation_expires() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_co_authored_proposal(1, vec![2, 3]));
+		assert_ok!(Proposal::accept_co_authorship(RuntimeOrigin::signed(2), 1));
+
+		run_to_block(501);
+
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert!(proposal.status == ProposalStatus::Rejected);
+		assert!(!CoAuthorInvitations::<Test>::contains_key(1));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(ActiveProposalCount::<Test>::get(0), 0);
+		System::assert_has_event(
+			crate::Event::<Test>::ProposalClosed(
+				1,
+				ProposalStatus::Rejected,
+				false,
+				Proposal::voter_root(1).unwrap(),
+			)
+			.into(),
+		);
+	});
+}
+
+fn amended_description() -> BoundedVec<u8, ConstU32<100>> {
+	let description: Vec<u8> = "Amended description of first proposal".into();
+	description.try_into().unwrap()
+}
+
+fn propose_amendment(who: u64, reset_votes: bool) -> DispatchResult {
+	Proposal::propose_amendment(RuntimeOrigin::signed(who), 1, amended_description(), reset_votes)
+}
+
+#[test]
+fn accepted_amendment_revises_proposal() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+
+		assert_ok!(propose_amendment(1, false));
+		System::assert_last_event(crate::Event::<Test>::AmendmentProposed(1, 1, 101).into());
+
+		assert_ok!(Proposal::vote_amendment(RuntimeOrigin::signed(4), 1, Vote::YES));
+		assert_ok!(Proposal::vote_amendment(RuntimeOrigin::signed(5), 1, Vote::NO));
+		assert_ok!(Proposal::vote_amendment(RuntimeOrigin::signed(6), 1, Vote::YES));
+
+		run_to_block(101);
+
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		let content_hash = BlakeTwo256::hash_of(&(&proposal.name, &proposal.description));
+		assert_eq!(proposal.description, amended_description());
+		assert_eq!(proposal.revision, 1);
+		assert_eq!(proposal.in_support.to_vec(), vec![2]);
+		assert!(!Amendments::<Test>::contains_key(1));
+
+		let revisions = Revisions::<Test>::get(1);
+		assert_eq!(revisions.len(), 1);
+		assert_eq!(revisions[0].author, 1);
+		assert_eq!(revisions[0].content_hash, content_hash);
+		assert_eq!(revisions[0].at, 101);
+		System::assert_has_event(crate::Event::<Test>::AmendmentClosed(1, true).into());
+		System::assert_has_event(
+			crate::Event::<Test>::ProposalRevised(1, 1, content_hash, false).into(),
+		);
+	});
+}
+
+#[test]
+fn accepted_amendment_can_reset_votes() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(3), 1, Vote::NO));
+
+		assert_ok!(propose_amendment(1, true));
+		assert_ok!(Proposal::vote_amendment(RuntimeOrigin::signed(5), 1, Vote::YES));
+		assert_ok!(Proposal::vote_amendment(RuntimeOrigin::signed(6), 1, Vote::YES));
+
+		run_to_block(101);
+
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert_eq!(proposal.revision, 1);
+		assert!(proposal.voter_accounts.is_empty());
+		assert_eq!(proposal.tally(), (0, 0));
+		assert!(VotesByAccount::<Test>::get(2).is_empty());
+		assert!(VotesByAccount::<Test>::get(3).is_empty());
+
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::NO));
+		assert_eq!(VotesByAccount::<Test>::get(2).into_inner(), vec![(1, Vote::NO)]);
+	});
+}
+
+#[test]
+fn rejected_amendment_leaves_proposal_unchanged() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+
+		assert_ok!(propose_amendment(1, true));
+		assert_ok!(Proposal::vote_amendment(RuntimeOrigin::signed(4), 1, Vote::YES));
+		assert_ok!(Proposal::vote_amendment(RuntimeOrigin::signed(5), 1, Vote::NO));
+
+		run_to_block(101);
+
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert_eq!(proposal.revision, 0);
+		assert!(proposal.description != amended_description());
+		assert!(Revisions::<Test>::get(1).is_empty());
+		System::assert_has_event(crate::Event::<Test>::AmendmentClosed(1, false).into());
+	});
+}
+
+#[test]
+fn amendment_needs_min_votes() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+
+		// A single vote for a resetting amendment cannot wipe the votes on the proposal.
+		assert_ok!(propose_amendment(1, true));
+		assert_ok!(Proposal::vote_amendment(RuntimeOrigin::signed(4), 1, Vote::YES));
+		run_to_block(101);
+
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert_eq!(proposal.revision, 0);
+		assert_eq!(proposal.in_support.to_vec(), vec![2]);
+		System::assert_has_event(crate::Event::<Test>::AmendmentClosed(1, false).into());
+	});
+}
+
+#[test]
+fn amendment_fails() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(propose_amendment(1, false), Error::<Test>::ProposalDoesNotExist);
+
+		create_proposal();
+		assert_noop!(
+			Proposal::vote_amendment(RuntimeOrigin::signed(4), 1, Vote::YES),
+			Error::<Test>::NoAmendment
+		);
+		ElectorateMembers::set(&Some(vec![1, 2, 4, 5]));
+		assert_noop!(propose_amendment(3, false), Error::<Test>::NotInElectorate);
+		ElectorateMembers::set(&None);
+
+		assert_ok!(propose_amendment(1, false));
+		assert_noop!(propose_amendment(1, false), Error::<Test>::AmendmentPending);
+		assert_noop!(
+			Proposal::vote_amendment(RuntimeOrigin::signed(1), 1, Vote::YES),
+			Error::<Test>::OwnerCannotVote
+		);
+
+		assert_ok!(Proposal::vote_amendment(RuntimeOrigin::signed(4), 1, Vote::YES));
+		assert_noop!(
+			Proposal::vote_amendment(RuntimeOrigin::signed(4), 1, Vote::NO),
+			Error::<Test>::DuplicateVote
+		);
+		assert_ok!(Proposal::vote_amendment(RuntimeOrigin::signed(5), 1, Vote::YES));
+
+		// The second revision fills the history.
+		run_to_block(101);
+		assert_ok!(propose_amendment(1, false));
+		assert_ok!(Proposal::vote_amendment(RuntimeOrigin::signed(4), 1, Vote::YES));
+		assert_ok!(Proposal::vote_amendment(RuntimeOrigin::signed(5), 1, Vote::YES));
+		run_to_block(201);
+		assert_eq!(Proposals::<Test>::get(1).unwrap().revision, 2);
+		assert_noop!(propose_amendment(1, false), Error::<Test>::TooManyRevisions);
+	});
+}
+
+#[test]
+fn amendment_by_any_member() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(propose_amendment(3, false));
+		System::assert_last_event(crate::Event::<Test>::AmendmentProposed(1, 3, 101).into());
+
+		// The proposer of the amendment cannot vote on it, nor can the authors.
+		assert_noop!(
+			Proposal::vote_amendment(RuntimeOrigin::signed(3), 1, Vote::YES),
+			Error::<Test>::OwnerCannotVote
+		);
+		assert_noop!(
+			Proposal::vote_amendment(RuntimeOrigin::signed(1), 1, Vote::YES),
+			Error::<Test>::OwnerCannotVote
+		);
+		assert_ok!(Proposal::vote_amendment(RuntimeOrigin::signed(4), 1, Vote::YES));
+		assert_ok!(Proposal::vote_amendment(RuntimeOrigin::signed(5), 1, Vote::YES));
+
+		run_to_block(101);
+		assert_eq!(Proposals::<Test>::get(1).unwrap().revision, 1);
+		assert_eq!(Revisions::<Test>::get(1)[0].author, 3);
+	});
+}
+
+#[test]
+fn amendment_fails_no_end_slot() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		for block in 101..165 {
+			AmendmentEnd::<Test>::insert(block, 2);
+		}
+		assert_noop!(propose_amendment(1, false), Error::<Test>::NoAmendmentSlot);
+
+		AmendmentEnd::<Test>::remove(164);
+		assert_ok!(propose_amendment(1, false));
+		assert_eq!(Amendments::<Test>::get(1).unwrap().end, 164);
+	});
+}
+
+#[test]
+fn amendment_must_close_before_deadline() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(Proposal::fast_track(RuntimeOrigin::root(), 1, 100));
+		assert_noop!(propose_amendment(1, false), Error::<Test>::AmendmentTooLate);
+
+		assert_ok!(Proposal::fast_track(RuntimeOrigin::root(), 1, 50));
+		run_to_block(51);
+		assert_noop!(propose_amendment(1, false), Error::<Test>::ProposalNotActive);
+	});
+}
+
+#[test]
+fn amendment_dropped_when_proposal_closes() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(propose_amendment(1, false));
+		assert_ok!(Proposal::vote_amendment(RuntimeOrigin::signed(4), 1, Vote::YES));
+
+		assert_ok!(Proposal::fast_track(RuntimeOrigin::root(), 1, 50));
+		run_to_block(101);
+
+		assert!(!Amendments::<Test>::contains_key(1));
+		assert!(AmendmentEnd::<Test>::get(101).is_none());
+		assert_eq!(Proposals::<Test>::get(1).unwrap().revision, 0);
+	});
+}
+
+fn edit_proposal(who: u64) -> DispatchResult {
+	let proposal_name: Vec<u8> = "Edited Proposal".into();
+	let bounded_proposal_name: BoundedVec<u8, ConstU32<20>> = proposal_name.try_into().unwrap();
+
+	Proposal::edit_proposal(
+		RuntimeOrigin::signed(who),
+		1,
+		bounded_proposal_name,
+		amended_description(),
+	)
+}
+
+#[test]
+fn edit_proposal_works() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+
+		run_to_block(5);
+		assert_ok!(edit_proposal(1));
+
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		let content_hash = BlakeTwo256::hash_of(&(&proposal.name, &proposal.description));
+		assert_eq!(proposal.name.to_vec(), b"Edited Proposal".to_vec());
+		assert_eq!(proposal.description, amended_description());
+		assert_eq!(proposal.revision, 1);
+
+		let revisions = Revisions::<Test>::get(1);
+		assert_eq!(revisions.len(), 1);
+		assert_eq!(revisions[0].author, 1);
+		assert_eq!(revisions[0].content_hash, content_hash);
+		assert_eq!(revisions[0].at, 5);
+		System::assert_last_event(crate::Event::<Test>::ProposalEdited(1, 1, content_hash).into());
+
+		// The history is bounded by `MaxRevisions`.
+		assert_ok!(edit_proposal(1));
+		assert_noop!(edit_proposal(1), Error::<Test>::TooManyRevisions);
+	});
+}
+
+#[test]
+fn edit_proposal_fails() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(edit_proposal(1), Error::<Test>::ProposalDoesNotExist);
+
+		create_proposal();
+		assert_noop!(edit_proposal(2), Error::<Test>::NotProposalOwner);
+
+		assert_ok!(propose_amendment(1, false));
+		assert_noop!(edit_proposal(1), Error::<Test>::AmendmentPending);
+
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert_noop!(edit_proposal(1), Error::<Test>::ProposalHasVotes);
+
+		run_to_block(14_401);
+		assert_noop!(edit_proposal(1), Error::<Test>::ProposalNotActive);
+	});
+}
+
+fn create_dependent_proposal(who: u64, track: u16, prerequisites: Vec<u32>) -> DispatchResult {
+	let proposal_name: Vec<u8> = "Dependent Proposal".into();
+	let bounded_proposal_name: BoundedVec<u8, ConstU32<20>> = proposal_name.try_into().unwrap();
+
+	let proposal_description: Vec<u8> = "Description of dependent proposal test".into();
+	let bounded_proposal_description: BoundedVec<u8, ConstU32<100>> =
+		proposal_description.try_into().unwrap();
+
+	Proposal::create_proposal(
+		RuntimeOrigin::signed(who),
+		track,
+		bounded_proposal_name,
+		bounded_proposal_description,
+		1,
+		None,
+		None,
+		None,
+		Default::default(),
+		prerequisites.try_into().unwrap(),
+		None,
+	)
+}
+
+#[test]
+fn dependent_proposal_opens_once_prerequisites_accepted() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(create_dependent_proposal(2, 0, vec![1]));
+
+		let proposal = Proposals::<Test>::get(2).unwrap();
+		assert!(proposal.status == ProposalStatus::AwaitingPrerequisites);
+		assert!(!proposal.is_active);
+		assert_eq!(Dependents::<Test>::get(1).to_vec(), vec![2]);
+		assert_noop!(
+			Proposal::vote(RuntimeOrigin::signed(3), 2, Vote::YES),
+			Error::<Test>::ProposalNotActive
+		);
+
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		run_to_block(14_401);
+
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::Accepted);
+		let proposal = Proposals::<Test>::get(2).unwrap();
+		assert!(proposal.status == ProposalStatus::VotingInProgress);
+		assert!(proposal.is_active);
+		assert_eq!(proposal.created_at, 14_401);
+		assert_eq!(proposal.deadline, 28_801);
+		assert!(!Prerequisites::<Test>::contains_key(2));
+		assert!(Dependents::<Test>::get(1).is_empty());
+		System::assert_has_event(crate::Event::<Test>::ProposalLaunched(2, 28_801).into());
+	});
+}
+
+#[test]
+fn rejected_prerequisite_cancels_dependents() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(create_dependent_proposal(2, 0, vec![1]));
+		assert_ok!(create_dependent_proposal(3, 1, vec![2]));
+
+		run_to_block(14_401);
+
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::Rejected);
+		assert!(Proposals::<Test>::get(2).unwrap().status == ProposalStatus::Cancelled);
+		assert!(Proposals::<Test>::get(3).unwrap().status == ProposalStatus::Cancelled);
+		assert!(!Prerequisites::<Test>::contains_key(2));
+		assert!(!Prerequisites::<Test>::contains_key(3));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(ActiveProposalCount::<Test>::get(0), 0);
+		assert_eq!(ActiveProposalCount::<Test>::get(1), 0);
+		System::assert_has_event(crate::Event::<Test>::DependencyFailed(2, 1).into());
+		System::assert_has_event(crate::Event::<Test>::DependencyFailed(3, 2).into());
+		System::assert_has_event(
+			crate::Event::<Test>::ProposalClosed(
+				3,
+				ProposalStatus::Cancelled,
+				false,
+				Proposal::voter_root(3).unwrap(),
+			)
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn cancellations_bounded_per_block() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(create_dependent_proposal(2, 0, vec![1]));
+		assert_ok!(create_dependent_proposal(3, 1, vec![2]));
+		assert_ok!(create_dependent_proposal(4, 1, vec![1]));
+
+		// Only `MaxCancellations` of the three dependents are cancelled in the closing block.
+		run_to_block(14_401);
+		let cancelled = (2..=4)
+			.filter(|id| Proposals::<Test>::get(id).unwrap().status == ProposalStatus::Cancelled)
+			.count();
+		assert_eq!(cancelled, 2);
+		assert_eq!(PendingCancellations::<Test>::iter().count(), 1);
+
+		run_to_block(14_402);
+		for id in 2..=4 {
+			assert!(Proposals::<Test>::get(id).unwrap().status == ProposalStatus::Cancelled);
+		}
+		assert_eq!(PendingCancellations::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn prerequisites_validated_on_create() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(create_dependent_proposal(2, 0, vec![1]), Error::<Test>::InvalidPrerequisite);
+
+		create_proposal();
+		assert_noop!(
+			create_dependent_proposal(2, 0, vec![1, 1]),
+			Error::<Test>::InvalidPrerequisite
+		);
+
+		assert_ok!(create_dependent_proposal(2, 0, vec![1]));
+		assert_ok!(create_dependent_proposal(2, 1, vec![1]));
+		assert_noop!(create_dependent_proposal(2, 2, vec![1]), Error::<Test>::TooManyDependents);
+
+		// An accepted prerequisite is not waited on.
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		run_to_block(14_401);
+		assert_ok!(create_dependent_proposal(3, 2, vec![1]));
+		assert!(Proposals::<Test>::get(4).unwrap().status == ProposalStatus::Preparing);
+	});
+}
+
+fn create_competing_proposal(
+	who: u64,
+	track: u16,
+	rival: u32,
+	anti_sniping: Option<AntiSniping<u64>>,
+) -> DispatchResult {
+	let proposal_name: Vec<u8> = "Competing Proposal".into();
+	let bounded_proposal_name: BoundedVec<u8, ConstU32<20>> = proposal_name.try_into().unwrap();
+
+	let proposal_description: Vec<u8> = "Description of competing proposal test".into();
+	let bounded_proposal_description: BoundedVec<u8, ConstU32<100>> =
+		proposal_description.try_into().unwrap();
+
+	Proposal::create_proposal(
+		RuntimeOrigin::signed(who),
+		track,
+		bounded_proposal_name,
+		bounded_proposal_description,
+		1,
+		anti_sniping,
+		None,
+		None,
+		Default::default(),
+		Default::default(),
+		Some(rival),
+	)
+}
+
+#[test]
+fn group_accepts_widest_margin() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		run_to_block(10);
+		assert_ok!(create_competing_proposal(2, 0, 1, None));
+
+		let proposal = Proposals::<Test>::get(2).unwrap();
+		assert_eq!(proposal.deadline, 14_401);
+		assert!(proposal.is_active);
+		assert_eq!(Groups::<Test>::get(1).to_vec(), vec![1, 2]);
+		assert_eq!(ProposalGroup::<Test>::get(2), Some(1));
+		System::assert_has_event(crate::Event::<Test>::JoinedGroup(2, 1, 14_401).into());
+
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(3), 1, Vote::YES));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(4), 1, Vote::YES));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(5), 1, Vote::NO));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(3), 2, Vote::YES));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(4), 2, Vote::YES));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(5), 2, Vote::YES));
+
+		run_to_block(14_401);
+
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::Superseded);
+		assert!(Proposals::<Test>::get(2).unwrap().status == ProposalStatus::Accepted);
+		assert!(Groups::<Test>::get(1).is_empty());
+		assert_eq!(ProposalGroup::<Test>::get(2), None);
+		assert_eq!(ActiveProposalCount::<Test>::get(0), 0);
+		System::assert_has_event(
+			crate::Event::<Test>::ProposalClosed(
+				1,
+				ProposalStatus::Superseded,
+				false,
+				Proposal::voter_root(1).unwrap(),
+			)
+			.into(),
+		);
+		System::assert_has_event(
+			crate::Event::<Test>::ProposalClosed(
+				2,
+				ProposalStatus::Accepted,
+				false,
+				Proposal::voter_root(2).unwrap(),
+			)
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn competing_proposals_close_with_group() {
+	new_test_ext().execute_with(|| {
+		ElectorateMembers::set(&Some(vec![1, 3, 4]));
+		create_proposal();
+		assert_ok!(create_competing_proposal(2, 0, 1, None));
+
+		// Two votes in support would decide a lone proposal.
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(3), 1, Vote::YES));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(4), 1, Vote::YES));
+		assert!(Proposals::<Test>::get(1).unwrap().is_active);
+
+		assert_noop!(
+			Proposal::fast_track(RuntimeOrigin::root(), 2, 100),
+			Error::<Test>::DeadlineFixedByGroup
+		);
+		assert_noop!(
+			Proposal::extend_deadline(RuntimeOrigin::signed(1), 1, 100),
+			Error::<Test>::DeadlineFixedByGroup
+		);
+
+		run_to_block(14_401);
+
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::Accepted);
+		assert!(Proposals::<Test>::get(2).unwrap().status == ProposalStatus::Rejected);
+	});
+}
+
+#[test]
+fn join_group_fails() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(create_competing_proposal(2, 0, 1, None), Error::<Test>::InvalidGroup);
+
+		create_proposal();
+		let anti_sniping = AntiSniping { window: 10, extension: 5, max_extension: 20 };
+		assert_noop!(
+			create_competing_proposal(2, 0, 1, Some(anti_sniping)),
+			Error::<Test>::IncompatibleGroup
+		);
+
+		assert_ok!(create_competing_proposal(2, 0, 1, None));
+		assert_ok!(create_competing_proposal(2, 2, 2, None));
+		assert_noop!(create_competing_proposal(3, 1, 1, None), Error::<Test>::GroupFull);
+	});
+}
+
+#[test]
+fn proposal_details_include_tally_and_voters() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(3), 1, Vote::NO));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(4), 1, Vote::YES));
+
+		let details = Proposal::proposal_details(1, Page { offset: 1, limit: 1 }).unwrap();
+		assert_eq!(details.owner, 1);
+		assert_eq!(details.name, b"First Proposal".to_vec());
+		assert!(details.status == ProposalStatus::VotingInProgress);
+		assert_eq!(details.deadline, 14_401);
+		assert_eq!(
+			details.tally,
+			Tally {
+				support: 2,
+				oppose: 1,
+				turnout: Perbill::one(),
+				approval: Perbill::from_rational(2u32, 3u32),
+			}
+		);
+		assert_eq!(details.voters, vec![(3, Vote::NO)]);
+
+		assert!(Proposal::proposal_details(2, Page::default()).is_none());
+	});
+}
+
+#[test]
+fn active_proposals_paginated() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(create_proposal_on_track(2, 0, 1));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(3), 2, Vote::NO));
+
+		let active = Proposal::active_proposals(Page { offset: 0, limit: 10 });
+		assert_eq!(active.len(), 2);
+		assert!(active.iter().all(|(_, details)| details.voters.is_empty()));
+		assert_eq!(Proposal::active_proposals(Page { offset: 1, limit: 10 }).len(), 1);
+		assert!(Proposal::active_proposals(Page { offset: 0, limit: 0 }).is_empty());
+
+		let mut projections = Proposal::projected_outcomes(Page { offset: 0, limit: 10 });
+		projections.sort_by_key(|(proposal_id, _)| *proposal_id);
+		assert!(projections[0].1.status == ProposalStatus::Accepted);
+		assert!(projections[1].1.status == ProposalStatus::Rejected);
+		assert_eq!(projections[0].1.approval_threshold, Perbill::from_percent(50));
+
+		assert_ok!(Proposal::fast_track(RuntimeOrigin::root(), 2, 10));
+		run_to_block(11);
+		let active = Proposal::active_proposals(Page { offset: 0, limit: 10 });
+		assert_eq!(
+			active
+				.into_iter()
+				.map(|(proposal_id, _)| proposal_id)
+				.collect::<Vec<_>>(),
+			vec![1]
+		);
+	});
+}
+
+#[test]
+fn proposals_filtered() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(create_proposal_on_track(2, 1, 1));
+		assert_ok!(create_proposal_on_track(3, 2, 10));
+		let page = Page { offset: 0, limit: 10 };
+		let ids = |filter| {
+			let mut ids = Proposal::proposals(filter, page)
+				.into_iter()
+				.map(|(proposal_id, _)| proposal_id)
+				.collect::<Vec<_>>();
+			ids.sort();
+			ids
+		};
+
+		assert_eq!(ids(ProposalFilter::default()), vec![1, 2, 3]);
+		assert_eq!(ids(ProposalFilter { owner: Some(2), ..Default::default() }), vec![2]);
+		assert_eq!(ids(ProposalFilter { track: Some(1), ..Default::default() }), vec![2]);
+		assert_eq!(
+			ids(ProposalFilter { status: Some(ProposalStatus::Preparing), ..Default::default() }),
+			vec![3]
+		);
+		assert_eq!(
+			ids(ProposalFilter {
+				status: Some(ProposalStatus::VotingInProgress),
+				owner: Some(1),
+				track: Some(0),
+			}),
+			vec![1]
+		);
+		assert!(ids(ProposalFilter { owner: Some(1), track: Some(1), status: None }).is_empty());
+		assert_eq!(
+			Proposal::proposals(ProposalFilter::default(), Page { offset: 2, limit: 10 }).len(),
+			1
+		);
+	});
+}
+
+#[test]
+fn updates_of_block() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+
+		let updates = Proposal::updates();
+		assert_eq!(
+			updates
+				.iter()
+				.map(|update| update.change.clone())
+				.collect::<Vec<_>>(),
+			vec![ProposalChange::Created, ProposalChange::VoteCast]
+		);
+		assert!(updates.iter().all(|update| update.proposal_id == 1
+			&& update.owner == 1
+			&& update.status == ProposalStatus::VotingInProgress
+			&& update.tally.support == 1));
+
+		System::reset_events();
+		assert!(Proposal::updates().is_empty());
+		assert_ok!(Proposal::fast_track(RuntimeOrigin::root(), 1, 10));
+		run_to_block(11);
+		let updates = Proposal::updates();
+		assert_eq!(updates.len(), 1);
+		assert_eq!(
+			updates[0].change,
+			ProposalChange::Closed { status: ProposalStatus::Accepted, tied: false }
+		);
+		assert_eq!(updates[0].status, ProposalStatus::Accepted);
+	});
+}
+
+#[test]
+fn updates_cover_status_changes() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(create_proposal_on_track(1, 2, 1));
+		System::reset_events();
+		assert_ok!(Proposal::place_decision_deposit(RuntimeOrigin::signed(3), 1));
+		assert_eq!(Proposal::updates()[0].change, ProposalChange::StatusChanged);
+
+		// Confirmation starts with the vote.
+		System::reset_events();
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		let updates = Proposal::updates();
+		assert!(updates
+			.iter()
+			.any(|update| update.change == ProposalChange::StatusChanged));
+		assert!(updates
+			.iter()
+			.all(|update| update.status == ProposalStatus::Confirming));
+
+		// Closing on confirmation is reported like any other close.
+		System::reset_events();
+		run_to_block(11);
+		let updates = Proposal::updates();
+		assert_eq!(updates.len(), 1);
+		assert_eq!(
+			updates[0].change,
+			ProposalChange::Closed { status: ProposalStatus::Accepted, tied: false }
+		);
+	});
+}
+
+#[test]
+fn validate_vote_without_voting() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_eq!(Proposal::validate_vote(1, 1, Vote::YES), Err(b"OwnerCannotVote".to_vec()));
+		assert_eq!(Proposal::validate_vote(2, 2, Vote::YES), Err(b"ProposalDoesNotExist".to_vec()));
+		assert_eq!(Proposal::validate_vote(1, 2, Vote::YES), Ok(()));
+		assert!(Proposals::<Test>::get(1).unwrap().voter_accounts.is_empty());
+
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert_eq!(Proposal::validate_vote(1, 2, Vote::NO), Err(b"DuplicateVote".to_vec()));
+	});
+}
+
+#[test]
+fn simulate_votes_outcome() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+
+		let simulation = Proposal::simulate_votes(1, vec![], None).unwrap();
+		assert_eq!(simulation.status, ProposalStatus::Accepted);
+		assert_eq!(simulation.tally.support, 1);
+
+		let simulation =
+			Proposal::simulate_votes(1, vec![(3, Vote::NO), (4, Vote::NO)], None).unwrap();
+		assert_eq!(simulation.status, ProposalStatus::Rejected);
+		assert!(!simulation.tied);
+		assert_eq!((simulation.tally.support, simulation.tally.oppose), (1, 2));
+
+		assert_eq!(
+			Proposal::simulate_votes(1, vec![(3, Vote::NO), (3, Vote::YES)], None),
+			Err(b"DuplicateVote".to_vec())
+		);
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert_eq!(proposal.tally(), (1, 0));
+		assert!(proposal.is_active);
+	});
+}
+
+#[test]
+fn simulate_votes_reports_tie_of_early_close() {
+	new_test_ext().execute_with(|| {
+		ElectorateMembers::set(&Some(vec![1, 2, 3]));
+		create_proposal();
+
+		// Every eligible account votes and the proposal closes on a tie.
+		let simulation =
+			Proposal::simulate_votes(1, vec![(2, Vote::YES), (3, Vote::NO)], None).unwrap();
+		assert_eq!(simulation.status, ProposalStatus::Rejected);
+		assert!(simulation.tied);
+		assert!(Proposals::<Test>::get(1).unwrap().is_active);
+	});
+}
+
+#[test]
+fn simulate_candle_ending_at_given_block() {
+	new_test_ext().execute_with(|| {
+		create_candle_proposal(100);
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+
+		run_to_block(14_350);
+		let votes = vec![(3, Vote::NO), (4, Vote::NO)];
+
+		// Without a candle block every vote counts, the same on every call.
+		for _ in 0..2 {
+			let simulation = Proposal::simulate_votes(1, votes.clone(), None).unwrap();
+			assert_eq!(simulation.status, ProposalStatus::Rejected);
+			assert_eq!((simulation.tally.support, simulation.tally.oppose), (1, 2));
+		}
+
+		// A candle block before the votes decides on the tally the ending period started with.
+		let simulation = Proposal::simulate_votes(1, votes, Some(14_340)).unwrap();
+		assert_eq!(simulation.status, ProposalStatus::Accepted);
+		assert_eq!(CandleCheckpoints::<Test>::iter_prefix(1).count(), 0);
+	});
+}
+
+#[test]
+fn indexes_follow_proposal() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert!(ProposalsByOwner::<Test>::contains_key(1, 1));
+		assert!(ProposalsByStatus::<Test>::contains_key(ProposalStatus::VotingInProgress, 1));
+		assert!(ProposalsByCreation::<Test>::contains_key(Proposal::creation_key(1), 1));
+
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert_ok!(Proposal::fast_track(RuntimeOrigin::root(), 1, 10));
+		run_to_block(11);
+		assert!(!ProposalsByStatus::<Test>::contains_key(ProposalStatus::VotingInProgress, 1));
+		assert!(ProposalsByStatus::<Test>::contains_key(ProposalStatus::Accepted, 1));
+	});
+}
+
+#[test]
+fn search_proposals() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		run_to_block(3);
+		assert_ok!(create_proposal_on_track(2, 0, 1));
+		run_to_block(5);
+		assert_ok!(create_proposal_on_track(3, 2, 10));
+		let ids = |query| {
+			let mut ids = Proposal::search(query, None, 10)
+				.proposals
+				.into_iter()
+				.map(|(proposal_id, _)| proposal_id)
+				.collect::<Vec<_>>();
+			ids.sort();
+			ids
+		};
+
+		let name = |name: NameMatch| ProposalQuery { name: Some(name), ..Default::default() };
+		assert_eq!(ids(name(NameMatch::Prefix(b"Track".to_vec()))), vec![2, 3]);
+		assert_eq!(ids(name(NameMatch::Contains(b"First".to_vec()))), vec![1]);
+		assert_eq!(ids(name(NameMatch::Prefix(b"First".to_vec()))), vec![1]);
+		assert!(ids(name(NameMatch::Prefix(b"Proposal".to_vec()))).is_empty());
+		assert_eq!(ids(ProposalQuery { owner: Some(2), ..Default::default() }), vec![2]);
+		assert_eq!(
+			ids(ProposalQuery { status: Some(ProposalStatus::Preparing), ..Default::default() }),
+			vec![3]
+		);
+		assert_eq!(
+			ids(ProposalQuery { created_from: Some(2), created_to: Some(4), ..Default::default() }),
+			vec![2]
+		);
+		assert_eq!(ids(ProposalQuery { created_from: Some(3), ..Default::default() }), vec![2, 3]);
+		assert_eq!(
+			ids(ProposalQuery {
+				name: Some(NameMatch::Contains(b"Track".to_vec())),
+				owner: Some(3),
+				..Default::default()
+			}),
+			vec![3]
+		);
+	});
+}
+
+#[test]
+fn search_with_cursor() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(create_proposal_on_track(2, 0, 1));
+		assert_ok!(create_proposal_on_track(3, 2, 10));
+
+		let mut found = Vec::new();
+		let mut cursor = None;
+		loop {
+			let result = Proposal::search(ProposalQuery::default(), cursor, 2);
+			assert!(result.proposals.len() <= 2);
+			found.extend(
+				result
+					.proposals
+					.into_iter()
+					.map(|(proposal_id, _)| proposal_id),
+			);
+			match result.next {
+				Some(next) => cursor = Some(next),
+				None => break,
+			}
+		}
+		found.sort();
+		assert_eq!(found, vec![1, 2, 3]);
+
+		// A zero limit returns a full page rather than an empty one with the same cursor.
+		let first = Proposal::search(ProposalQuery::default(), None, 0);
+		assert_eq!(first.proposals.len(), 3);
+		assert_eq!(first.next, None);
+	});
+}
+
+#[test]
+fn votes_by_account_bounded() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(create_proposal_on_track(3, 0, 1));
+		assert_ok!(create_proposal_on_track(1, 1, 1));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 2, Vote::NO));
+		assert_eq!(VotesByAccount::<Test>::get(2).to_vec(), vec![(1, Vote::YES), (2, Vote::NO)]);
+
+		// The oldest vote is dropped once the index is full.
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 3, Vote::YES));
+		assert_eq!(VotesByAccount::<Test>::get(2).to_vec(), vec![(2, Vote::NO), (3, Vote::YES)]);
+		assert_eq!(
+			Proposal::votes_of(&2, Page { offset: 0, limit: 10 }),
+			vec![(3, Vote::YES), (2, Vote::NO)]
+		);
+		assert_eq!(Proposal::votes_of(&2, Page { offset: 1, limit: 10 }), vec![(2, Vote::NO)]);
+	});
+}
+
+#[test]
+fn statistics_tracked() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(create_proposal_on_track(3, 0, 1));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 2, Vote::NO));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(4), 1, Vote::YES));
+
+		let stats = Proposal::statistics();
+		assert_eq!((stats.total_proposals, stats.total_votes, stats.unique_voters), (2, 3, 2));
+		assert_eq!(stats.decided(), 0);
+		assert_eq!(stats.average_turnout(), None);
+
+		// Simulated votes are not counted.
+		assert_ok!(Proposal::simulate_votes(1, vec![(5, Vote::NO)], None));
+		assert_eq!(Proposal::statistics(), stats);
+
+		assert_ok!(Proposal::fast_track(RuntimeOrigin::root(), 1, 10));
+		run_to_block(11);
+		let stats = Proposal::statistics();
+		assert_eq!((stats.accepted, stats.rejected, stats.decided()), (1, 0, 1));
+		assert_eq!((stats.voted, stats.average_votes()), (1, 2));
+
+		// Without a fixed electorate there is no turnout to record.
+		assert_eq!(stats.average_turnout(), None);
+	});
+}
+
+#[test]
+fn statistics_turnout_over_opened_proposals() {
+	new_test_ext().execute_with(|| {
+		ElectorateMembers::set(&Some(vec![1, 2, 3, 4, 5]));
+		create_proposal();
+		assert_ok!(create_co_authored_proposal(2, vec![3]));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+
+		// The invitation expires, so the second proposal never opens.
+		run_to_block(501);
+		let stats = Proposal::statistics();
+		assert_eq!((stats.rejected, stats.voted), (1, 0));
+		assert_eq!(stats.average_turnout(), None);
+
+		// One vote out of the four members other than the owner.
+		run_to_block(14_401);
+		let stats = Proposal::statistics();
+		assert_eq!((stats.decided(), stats.voted, stats.voted_votes), (2, 1, 1));
+		assert_eq!(stats.average_turnout(), Some(Perbill::from_percent(25)));
+	});
+}
+
+#[test]
+fn tally_checkpoints_thinned() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		run_to_block(2);
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(3), 1, Vote::NO));
+		assert_eq!(Proposal::tally_checkpoints(1).to_vec(), vec![(1, 1, 0), (2, 1, 1)]);
+
+		// A full series keeps every other checkpoint before taking the next one.
+		run_to_block(3);
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(4), 1, Vote::YES));
+		assert_eq!(Proposal::tally_checkpoints(1).to_vec(), vec![(1, 1, 0), (3, 2, 1)]);
+
+		// Votes of the same block leave a single checkpoint.
+		assert_ok!(create_proposal_on_track(3, 0, 1));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 2, Vote::YES));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(4), 2, Vote::NO));
+		assert_eq!(Proposal::tally_checkpoints(2).to_vec(), vec![(3, 1, 1)]);
+
+		// The series outlives the proposal.
+		run_to_block(14_401);
+		assert!(!Proposal::proposals(1).unwrap().is_active);
+		assert_eq!(Proposal::tally_checkpoints(1).to_vec(), vec![(1, 1, 0), (3, 2, 1)]);
+	});
+}
+
+#[test]
+fn archive_proposal_works() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		run_to_block(14_400 + 100);
+
+		assert_ok!(Proposal::archive_proposal(RuntimeOrigin::signed(5), 1));
+		assert!(!TallyCheckpoints::<Test>::contains_key(1));
+		assert!(Proposal::proposals(1).is_some());
+		assert!(Proposal::voter_root(1).is_some());
+		System::assert_last_event(crate::Event::<Test>::ProposalArchived(1).into());
+	});
+}
+
+#[test]
+fn archive_proposal_fails() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Proposal::archive_proposal(RuntimeOrigin::signed(5), 1),
+			Error::<Test>::ProposalDoesNotExist
+		);
+
+		create_proposal();
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert_noop!(
+			Proposal::archive_proposal(RuntimeOrigin::signed(5), 1),
+			Error::<Test>::ProposalNotClosed
+		);
+
+		run_to_block(14_401);
+		assert_noop!(
+			Proposal::archive_proposal(RuntimeOrigin::signed(5), 1),
+			Error::<Test>::ArchivePeriodNotOver
+		);
+
+		run_to_block(14_400 + 100);
+		assert_ok!(Proposal::archive_proposal(RuntimeOrigin::signed(5), 1));
+		assert_noop!(
+			Proposal::archive_proposal(RuntimeOrigin::signed(5), 1),
+			Error::<Test>::NothingToArchive
+		);
+	});
+}
+
+#[test]
+fn voter_proofs_against_root() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert!(Proposal::voter_proof(1, &2).is_none());
+
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(3), 1, Vote::NO));
+		run_to_block(14_401);
+
+		let root = Proposal::voter_root(1).unwrap();
+		let proof = Proposal::voter_proof(1, &3).unwrap();
+		assert_eq!((proof.root, proof.choice.clone()), (root, Vote::NO));
+		assert_eq!((proof.number_of_leaves, proof.leaf_index), (2, 1));
+		assert!(Proposal::verify_voter_proof(&3, &proof));
+		assert!(Proposal::verify_voter_proof(&2, &Proposal::voter_proof(1, &2).unwrap()));
+
+		// A proof does not carry over to another account or choice.
+		assert!(!Proposal::verify_voter_proof(&2, &proof));
+		let mut forged = proof;
+		forged.choice = Vote::YES;
+		assert!(!Proposal::verify_voter_proof(&3, &forged));
+		assert!(Proposal::voter_proof(1, &4).is_none());
+	});
+}
+
+#[test]
+fn votes_of_account() {
+	new_test_ext().execute_with(|| {
+		create_proposal();
+		assert_ok!(create_proposal_on_track(3, 0, 1));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 1, Vote::YES));
+		assert_ok!(Proposal::vote(RuntimeOrigin::signed(2), 2, Vote::NO));
+
+		let mut votes = Proposal::votes_of(&2, Page { offset: 0, limit: 10 });
+		votes.sort_by_key(|(proposal_id, _)| *proposal_id);
+		assert_eq!(votes, vec![(1, Vote::YES), (2, Vote::NO)]);
+		assert_eq!(Proposal::votes_of(&2, Page { offset: 0, limit: 1 }).len(), 1);
+		assert!(Proposal::votes_of(&4, Page { offset: 0, limit: 10 }).is_empty());
+	});
+}
+
+#[test]
+fn migration_translates_old_proposals() {
+	use crate::migrations::v1::{MigrateV0ToV1, OldProposal};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(500_000);
+		StorageVersion::new(0).put::<Proposal>();
+		let old = OldProposal::<u64, ConstU32<20>, ConstU32<100>, ConstU32<3>> {
+			owner: 1,
+			name: b"Old Proposal".to_vec().try_into().unwrap(),
+			description: b"Description of old proposal".to_vec().try_into().unwrap(),
+			is_active: true,
+			voter_accounts: vec![2].try_into().unwrap(),
+			in_support: vec![2].try_into().unwrap(),
+			in_oppose: Default::default(),
+			status: ProposalStatus::VotingInProgress,
+		};
+		frame_support::storage::unhashed::put(&Proposals::<Test>::hashed_key_for(1), &old);
+		ProposalExpireTime::<Test>::insert(514_400, 1);
+
+		MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+		let proposal = Proposals::<Test>::get(1).unwrap();
+		assert_eq!(proposal.owner, 1);
+		assert_eq!(proposal.in_support.to_vec(), vec![2]);
+		assert!(proposal.status == ProposalStatus::VotingInProgress);
+		// Taken to have been created 30 days, the longest duration, before its deadline.
+		assert_eq!((proposal.deadline, proposal.created_at), (514_400, 82_400));
+		assert_eq!(proposal.track, 0);
+		assert!(ProposalsByOwner::<Test>::contains_key(1, 1));
+		assert!(ProposalsByStatus::<Test>::contains_key(ProposalStatus::VotingInProgress, 1));
+		assert!(ProposalsByCreation::<Test>::contains_key(Proposal::creation_key(82_400), 1));
+		assert_eq!(ActiveProposalCount::<Test>::get(0), 1);
+		assert_eq!(Proposal::on_chain_storage_version(), 1);
+
+		// The old proposal closes at its deadline like any other.
+		run_to_block(514_400);
+		assert!(Proposals::<Test>::get(1).unwrap().status == ProposalStatus::Accepted);
+	});
+}
+
+#[test]
+fn create_proposal_fails_no_expiry_slot() {
+	new_test_ext().execute_with(|| {
+		let deadline = System::block_number() + 14_400;
+		for block in deadline..deadline + 64 {
+			ProposalExpireTime::<Test>::insert(block, 99);
+		}
+
+		assert_noop!(
+			Proposal::create_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				"First Proposal".as_bytes().to_vec().try_into().unwrap(),
+				"Description of first proposal test"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				1,
+				None,
+				None,
+				None,
+				Default::default(),
+				Default::default(),
+				None
+			),
+			Error::<Test>::NoExpirySlot
+		);
+
+		ProposalExpireTime::<Test>::remove(deadline + 63);
+		create_proposal();
+		assert_eq!(Proposals::<Test>::get(1).unwrap().deadline, deadline + 63);
+	});
+}
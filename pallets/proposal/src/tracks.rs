@@ -0,0 +1,55 @@
+// Governance tracks.
+
+use frame_support::{
+	pallet_prelude::{MaxEncodedLen, Parameter},
+	sp_runtime::{traits::BadOrigin, Perbill},
+};
+
+/// Parameters shared by every proposal on a track.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TrackInfo<Balance> {
+	/// Name of the track, for display purposes.
+	pub name: &'static str,
+	/// Deposit held from the proposal owner until the proposal closes.
+	pub submission_deposit: Balance,
+	/// Deposit needed for a proposal to leave the preparing phase. Proposals on a track without
+	/// a decision deposit are decided right away.
+	pub decision_deposit: Balance,
+	/// Shortest voting period a proposal can ask for, in days.
+	pub min_duration: u32,
+	/// Longest voting period a proposal can run for, in days, including extensions.
+	pub max_duration: u32,
+	/// Share of the electorate that must vote for a proposal to pass.
+	pub min_turnout: Perbill,
+	/// Share of the votes in support above which a proposal passes. A tally exactly on the
+	/// threshold is a tie.
+	pub approval_threshold: Perbill,
+	/// Maximum number of proposals open on the track at the same time.
+	pub max_active: u32,
+	/// Number of blocks a proposal has to keep passing for to be accepted before its deadline.
+	/// Zero disables early acceptance.
+	pub confirm_period: u32,
+}
+
+/// Information on the governance tracks proposals are created on.
+pub trait TracksInfo<AccountId, Balance> {
+	/// Identifier of a track.
+	type Id: Parameter + MaxEncodedLen + Copy + Ord + 'static;
+
+	/// Origin that submits proposals.
+	type RuntimeOrigin;
+
+	/// All tracks with their parameters.
+	fn tracks() -> &'static [(Self::Id, TrackInfo<Balance>)];
+
+	/// Check that `origin` may create a proposal on track `id`, returning the proposal owner.
+	fn authorize(id: Self::Id, origin: Self::RuntimeOrigin) -> Result<AccountId, BadOrigin>;
+
+	/// Parameters of track `id`, if it exists.
+	fn info(id: Self::Id) -> Option<&'static TrackInfo<Balance>> {
+		Self::tracks()
+			.iter()
+			.find(|(track_id, _)| *track_id == id)
+			.map(|(_, info)| info)
+	}
+}
@@ -1,17 +1,37 @@
+use crate::constants::MAX_PAGE_SIZE;
 use codec::{Decode, Encode};
-use frame_support::{pallet_prelude::Get, BoundedVec};
+use frame_support::{
+	pallet_prelude::Get,
+	sp_runtime::{
+		traits::{Saturating, Zero},
+		Perbill,
+	},
+	BoundedVec,
+};
 use scale_info::TypeInfo;
+use sp_std::vec::Vec;
 
 #[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Default)]
-#[scale_info(skip_type_params(NameLimit, DescriptionLimit, AccountLimit))]
+#[scale_info(skip_type_params(
+	NameLimit,
+	DescriptionLimit,
+	AccountLimit,
+	MaxExtensions,
+	MaxCoAuthors
+))]
 pub struct Proposal<
 	AccountId,
+	BlockNumber,
+	TrackId,
 	NameLimit: Get<u32>,
 	DescriptionLimit: Get<u32>,
 	AccountLimit: Get<u32>,
+	MaxExtensions: Get<u32>,
+	MaxCoAuthors: Get<u32>,
 	ProposalStatus,
 > {
 	pub owner: AccountId,
+	pub co_authors: BoundedVec<AccountId, MaxCoAuthors>,
 	pub name: BoundedVec<u8, NameLimit>,
 	pub description: BoundedVec<u8, DescriptionLimit>,
 	pub is_active: bool,
@@ -19,16 +39,529 @@ pub struct Proposal<
 	pub in_support: BoundedVec<AccountId, AccountLimit>,
 	pub in_oppose: BoundedVec<AccountId, AccountLimit>,
 	pub status: ProposalStatus,
+	pub deadline: BlockNumber,
+	pub created_at: BlockNumber,
+	pub extensions: BoundedVec<DeadlineExtension<BlockNumber>, MaxExtensions>,
+	pub anti_sniping: Option<AntiSniping<BlockNumber>>,
+	pub auto_extended: BlockNumber,
+	pub candle_period: Option<BlockNumber>,
+	pub tie_break: Option<TieBreak>,
+	pub owner_preference: Option<Vote>,
+	pub last_lead: Leading,
+	pub track: TrackId,
+	pub confirm_end: Option<BlockNumber>,
+	pub curve_check: Option<BlockNumber>,
+	pub revision: u32,
+}
+
+impl<
+		AccountId: PartialEq,
+		BlockNumber,
+		TrackId,
+		NameLimit: Get<u32>,
+		DescriptionLimit: Get<u32>,
+		AccountLimit: Get<u32>,
+		MaxExtensions: Get<u32>,
+		MaxCoAuthors: Get<u32>,
+		ProposalStatus,
+	>
+	Proposal<
+		AccountId,
+		BlockNumber,
+		TrackId,
+		NameLimit,
+		DescriptionLimit,
+		AccountLimit,
+		MaxExtensions,
+		MaxCoAuthors,
+		ProposalStatus,
+	>
+{
+	/// Whether `who` is the owner or one of the co-authors of the proposal.
+	pub fn is_author(&self, who: &AccountId) -> bool {
+		self.owner == *who || self.co_authors.contains(who)
+	}
+
+	/// How `who` voted on the proposal, if they did.
+	pub fn vote_of(&self, who: &AccountId) -> Option<Vote> {
+		if self.in_support.contains(who) {
+			Some(Vote::YES)
+		} else if self.in_oppose.contains(who) {
+			Some(Vote::NO)
+		} else {
+			None
+		}
+	}
+
+	/// Current number of votes in support and in opposition.
+	pub fn tally(&self) -> (u32, u32) {
+		(self.in_support.len() as u32, self.in_oppose.len() as u32)
+	}
+
+	/// The side currently ahead in the vote.
+	pub fn leading(&self) -> Leading {
+		let (support, oppose) = self.tally();
+
+		if support > oppose {
+			Leading::Support
+		} else if oppose > support {
+			Leading::Oppose
+		} else {
+			Leading::Tie
+		}
+	}
+}
+
+/// A single push-back of a proposal's deadline.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+pub struct DeadlineExtension<BlockNumber> {
+	/// Block at which the extension was made.
+	pub extended_at: BlockNumber,
+	/// Deadline before the extension.
+	pub old_deadline: BlockNumber,
+	/// Deadline after the extension.
+	pub new_deadline: BlockNumber,
+	/// Whether the proposal owner made the extension, rather than `ExtendOrigin`.
+	pub by_owner: bool,
 }
 
 #[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum Vote {
 	YES,
 	NO,
 }
 
+/// Funds held from an account for a proposal.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+pub struct Deposit<AccountId, Balance> {
+	/// Account the funds are held from.
+	pub who: AccountId,
+	/// Amount held.
+	pub amount: Balance,
+}
+
+/// Co-authors a proposal waits on before it opens.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+#[scale_info(skip_type_params(MaxCoAuthors))]
+pub struct Invitation<AccountId, MaxCoAuthors: Get<u32>> {
+	/// Voting period the proposal runs for once it opens, in days.
+	pub duration: u32,
+	/// Invited accounts that have not accepted yet.
+	pub invited: BoundedVec<AccountId, MaxCoAuthors>,
+}
+
+/// Earlier proposals a proposal waits on to be accepted before it opens.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+#[scale_info(skip_type_params(MaxPrerequisites))]
+pub struct Dependency<ProposalId, MaxPrerequisites: Get<u32>> {
+	/// Voting period the proposal runs for once it opens, in days.
+	pub duration: u32,
+	/// Prerequisites that have not been accepted yet.
+	pub pending: BoundedVec<ProposalId, MaxPrerequisites>,
+}
+
+/// Replacement description of an open proposal, put to its own vote.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+#[scale_info(skip_type_params(DescriptionLimit, AccountLimit))]
+pub struct Amendment<AccountId, BlockNumber, DescriptionLimit: Get<u32>, AccountLimit: Get<u32>> {
+	/// Account that submitted the amendment.
+	pub proposer: AccountId,
+	/// Description replacing the one of the proposal if the amendment is accepted.
+	pub description: BoundedVec<u8, DescriptionLimit>,
+	/// Whether accepting the amendment clears the votes cast on the proposal.
+	pub reset_votes: bool,
+	/// Accounts that voted for the amendment.
+	pub in_support: BoundedVec<AccountId, AccountLimit>,
+	/// Accounts that voted against the amendment.
+	pub in_oppose: BoundedVec<AccountId, AccountLimit>,
+	/// Block at which the vote on the amendment closes.
+	pub end: BlockNumber,
+}
+
+impl<AccountId: PartialEq, BlockNumber, DescriptionLimit: Get<u32>, AccountLimit: Get<u32>>
+	Amendment<AccountId, BlockNumber, DescriptionLimit, AccountLimit>
+{
+	/// Whether `who` has voted on the amendment.
+	pub fn has_voted(&self, who: &AccountId) -> bool {
+		self.in_support.contains(who) || self.in_oppose.contains(who)
+	}
+
+	/// Whether at least `min_votes` accounts voted on the amendment, and more of them for it than
+	/// against it.
+	pub fn is_accepted(&self, min_votes: u32) -> bool {
+		let votes = self.in_support.len().saturating_add(self.in_oppose.len());
+		votes >= min_votes as usize && self.in_support.len() > self.in_oppose.len()
+	}
+}
+
+/// A revision of a proposal's description.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+pub struct Revision<AccountId, BlockNumber, Hash> {
+	/// Account whose change created the revision.
+	pub author: AccountId,
+	/// Hash of the encoded name and description of the revision.
+	pub content_hash: Hash,
+	/// Block at which the revision was made.
+	pub at: BlockNumber,
+}
+
+/// A proposal waiting in the seconding queue.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+#[scale_info(skip_type_params(MaxSeconds))]
+pub struct QueueEntry<AccountId, Balance, BlockNumber, MaxSeconds: Get<u32>> {
+	/// Voting period the proposal runs for once launched, in days.
+	pub duration: u32,
+	/// Block at which the proposal entered the queue.
+	pub queued_at: BlockNumber,
+	/// Accounts that seconded the proposal, with the deposit each of them backed it with.
+	pub seconds: BoundedVec<Deposit<AccountId, Balance>, MaxSeconds>,
+}
+
+impl<AccountId, Balance: Saturating + Zero + Copy, BlockNumber, MaxSeconds: Get<u32>>
+	QueueEntry<AccountId, Balance, BlockNumber, MaxSeconds>
+{
+	/// Total deposit the proposal is backed with.
+	pub fn backing(&self) -> Balance {
+		self.seconds
+			.iter()
+			.fold(Zero::zero(), |total, second| total.saturating_add(second.amount))
+	}
+}
+
+/// Side that is ahead in a proposal's vote.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, Debug, Default)]
+pub enum Leading {
+	/// More votes in support.
+	Support,
+	/// More votes in opposition.
+	Oppose,
+	/// Equal number of votes on both sides.
+	#[default]
+	Tie,
+}
+
+/// How a proposal whose vote ends in a tie is decided.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, Debug)]
+pub enum TieBreak {
+	/// Ties are rejected.
+	Reject,
+	/// Ties are accepted.
+	Accept,
+	/// Ties go to the side that led last before the tie, and are rejected if no side ever led.
+	StatusQuo,
+	/// Ties follow the owner's preference, and are rejected if the owner did not give one.
+	OwnerPreference,
+	/// Ties are decided by a draw from the configured `Randomness`.
+	Random,
+}
+
+/// Settings that push a proposal's deadline back when a late vote changes the leading side.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+pub struct AntiSniping<BlockNumber> {
+	/// Number of blocks before the deadline in which a lead change extends the deadline.
+	pub window: BlockNumber,
+	/// Number of blocks the deadline is pushed back by on each lead change.
+	pub extension: BlockNumber,
+	/// Maximum number of blocks the deadline can be pushed back by in total.
+	pub max_extension: BlockNumber,
+}
+
+/// Threshold that decays over the lifetime of a proposal, from `begin` at its creation to `end`
+/// at its deadline.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+pub enum Curve {
+	/// Falls at a constant rate.
+	LinearDecreasing { begin: Perbill, end: Perbill },
+	/// Falls along `(1 - x) / (1 + factor * x)`: quickly at first, then ever more slowly. A
+	/// `factor` of zero is the same as a linear decrease.
+	Reciprocal { begin: Perbill, end: Perbill, factor: u32 },
+}
+
+impl Curve {
+	/// Value of the curve once `progress` of the proposal's lifetime has passed.
+	pub fn threshold(&self, progress: Perbill) -> Perbill {
+		let (begin, end, factor) = match *self {
+			Curve::LinearDecreasing { begin, end } => (begin, end, 0),
+			Curve::Reciprocal { begin, end, factor } => (begin, end, factor),
+		};
+
+		let whole = Perbill::one().deconstruct() as u64;
+		let elapsed = progress.deconstruct() as u64;
+		let remaining = Perbill::from_rational(
+			whole.saturating_sub(elapsed),
+			whole.saturating_add((factor as u64).saturating_mul(elapsed)),
+		);
+		end.saturating_add(remaining * begin.saturating_sub(end))
+	}
+}
+
+/// Page of results returned by the runtime API.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, Debug, Default)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Page {
+	/// Number of results skipped.
+	pub offset: u32,
+	/// Maximum number of results returned, capped by `MAX_PAGE_SIZE`.
+	pub limit: u32,
+}
+
+impl Page {
+	/// First page, holding as many results as a page can.
+	pub const FIRST: Self = Self { offset: 0, limit: MAX_PAGE_SIZE };
+
+	/// Number of results the page holds at most.
+	pub fn size(&self) -> usize {
+		self.limit.min(MAX_PAGE_SIZE) as usize
+	}
+}
+
+/// Criteria a proposal has to meet to be listed by the runtime API. Unset criteria match any
+/// proposal.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ProposalFilter<AccountId, TrackId> {
+	/// Status the proposal has.
+	pub status: Option<ProposalStatus>,
+	/// Account that created the proposal.
+	pub owner: Option<AccountId>,
+	/// Track, and so community, the proposal was created on.
+	pub track: Option<TrackId>,
+}
+
+impl<AccountId, TrackId> Default for ProposalFilter<AccountId, TrackId> {
+	fn default() -> Self {
+		Self { status: None, owner: None, track: None }
+	}
+}
+
+impl<AccountId: PartialEq, TrackId: PartialEq> ProposalFilter<AccountId, TrackId> {
+	/// Whether a proposal with the given `status`, `owner` and `track` meets the criteria.
+	pub fn matches(&self, status: &ProposalStatus, owner: &AccountId, track: &TrackId) -> bool {
+		self.status.as_ref().map_or(true, |s| s == status)
+			&& self.owner.as_ref().map_or(true, |o| o == owner)
+			&& self.track.as_ref().map_or(true, |t| t == track)
+	}
+}
+
+/// How a search matches the name of a proposal.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum NameMatch {
+	/// The name starts with the given bytes.
+	Prefix(Vec<u8>),
+	/// The name contains the given bytes.
+	Contains(Vec<u8>),
+}
+
+impl NameMatch {
+	/// Whether `name` matches.
+	pub fn matches(&self, name: &[u8]) -> bool {
+		match self {
+			NameMatch::Prefix(prefix) => name.starts_with(prefix),
+			NameMatch::Contains(part) => {
+				part.is_empty() || name.windows(part.len()).any(|window| window == &part[..])
+			},
+		}
+	}
+}
+
+/// Criteria of a proposal search through the runtime API. Unset criteria match any proposal.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ProposalQuery<AccountId, BlockNumber> {
+	pub name: Option<NameMatch>,
+	pub owner: Option<AccountId>,
+	pub status: Option<ProposalStatus>,
+	/// Earliest block the proposal was created, or launched, at.
+	pub created_from: Option<BlockNumber>,
+	/// Latest block the proposal was created, or launched, at.
+	pub created_to: Option<BlockNumber>,
+}
+
+impl<AccountId, BlockNumber> Default for ProposalQuery<AccountId, BlockNumber> {
+	fn default() -> Self {
+		Self { name: None, owner: None, status: None, created_from: None, created_to: None }
+	}
+}
+
+/// Page of search results returned by the runtime API.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+pub struct SearchResult<ProposalId, AccountId, BlockNumber, TrackId> {
+	/// Matching proposals with their current tally, without their voters.
+	pub proposals: Vec<(ProposalId, ProposalDetails<AccountId, BlockNumber, TrackId>)>,
+	/// Cursor continuing the search where this page stopped, unless the search is complete.
+	pub next: Option<Vec<u8>>,
+}
+
+/// Current tally of a proposal.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Tally {
+	/// Number of votes in support.
+	pub support: u32,
+	/// Number of votes in opposition.
+	pub oppose: u32,
+	/// Share of the eligible voters that voted.
+	pub turnout: Perbill,
+	/// Share of the votes cast in support.
+	pub approval: Perbill,
+}
+
+/// Proposal as returned by the runtime API.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+pub struct ProposalDetails<AccountId, BlockNumber, TrackId> {
+	pub owner: AccountId,
+	pub co_authors: Vec<AccountId>,
+	pub name: Vec<u8>,
+	pub description: Vec<u8>,
+	pub track: TrackId,
+	pub status: ProposalStatus,
+	pub is_active: bool,
+	pub created_at: BlockNumber,
+	pub deadline: BlockNumber,
+	pub revision: u32,
+	pub tally: Tally,
+	/// Page of the accounts that voted, with how they voted.
+	pub voters: Vec<(AccountId, Vote)>,
+}
+
+/// Change made to a proposal by an event.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum ProposalChange {
+	/// The proposal was created.
+	Created,
+	/// A vote was cast on the proposal.
+	VoteCast,
+	/// The proposal moved to another status while staying open.
+	StatusChanged,
+	/// The proposal closed with `status`.
+	Closed { status: ProposalStatus, tied: bool },
+}
+
+/// Change made to a proposal in a block, with the state of the proposal at the end of the
+/// block.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ProposalUpdate<ProposalId, AccountId, TrackId> {
+	pub proposal_id: ProposalId,
+	pub change: ProposalChange,
+	pub owner: AccountId,
+	pub track: TrackId,
+	pub status: ProposalStatus,
+	pub tally: Tally,
+}
+
+/// Outcome a proposal would have if it closed now.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Projection {
+	/// Status the proposal would close with.
+	pub status: ProposalStatus,
+	/// Whether the tally is tied.
+	pub tied: bool,
+	/// Minimum turnout the proposal has to reach now.
+	pub min_turnout: Perbill,
+	/// Approval the proposal has to exceed now.
+	pub approval_threshold: Perbill,
+}
+
+/// Outcome a proposal would close with after hypothetical votes.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Simulation {
+	/// Status the proposal would close with.
+	pub status: ProposalStatus,
+	/// Whether the closing tally would be tied.
+	pub tied: bool,
+	/// Tally of the proposal with the hypothetical votes.
+	pub tally: Tally,
+}
+
+/// Merkle proof that a vote is part of the voter set a proposal committed to when it closed.
+/// The leaves of the tree are the SCALE-encoded `(account, choice, weight)` of the voters, in the
+/// order they voted.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct VoterProof<Hash> {
+	/// Root of the voter set, as emitted when the proposal closed.
+	pub root: Hash,
+	/// How the voter voted.
+	pub choice: Vote,
+	/// Weight the vote counted with.
+	pub weight: u32,
+	/// Hashes of the siblings on the path from the leaf up to the root.
+	pub proof: Vec<Hash>,
+	pub number_of_leaves: u32,
+	pub leaf_index: u32,
+}
+
+/// Running statistics over all proposals.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, Debug, Default)]
+pub struct Statistics {
+	/// Number of proposals created.
+	pub total_proposals: u32,
+	/// Number of proposals closed as accepted.
+	pub accepted: u32,
+	/// Number of proposals closed as rejected.
+	pub rejected: u32,
+	/// Number of proposals cancelled.
+	pub cancelled: u32,
+	/// Number of proposals superseded by a competing proposal.
+	pub superseded: u32,
+	/// Number of votes cast.
+	pub total_votes: u64,
+	/// Number of accounts that voted at least once.
+	pub unique_voters: u32,
+	/// Number of proposals closed as accepted, rejected or superseded after being open for
+	/// voting.
+	pub voted: u32,
+	/// Number of votes cast on the proposals counted in `voted`.
+	pub voted_votes: u64,
+	/// Number of proposals counted in `voted` that closed while the electorate had a fixed size.
+	pub turnout_samples: u32,
+	/// Sum of the turnout of the proposals counted in `turnout_samples`, in parts per billion.
+	pub turnout_sum: u64,
+}
+
+impl Statistics {
+	/// Number of proposals closed as accepted, rejected or superseded.
+	pub fn decided(&self) -> u32 {
+		self.accepted
+			.saturating_add(self.rejected)
+			.saturating_add(self.superseded)
+	}
+
+	/// Average number of votes cast on the decided proposals that were open for voting.
+	pub fn average_votes(&self) -> u64 {
+		self.voted_votes / u64::from(self.voted).max(1)
+	}
+
+	/// Average turnout of the decided proposals that were open for voting, if any closed while
+	/// the electorate had a fixed size.
+	pub fn average_turnout(&self) -> Option<Perbill> {
+		if self.turnout_samples == 0 {
+			return None;
+		}
+		let average = self.turnout_sum / u64::from(self.turnout_samples);
+		Some(Perbill::from_parts(average.min(Perbill::ACCURACY.into()) as u32))
+	}
+}
+
 /// Result of proposal.
-#[derive(Eq, PartialEq, Clone, TypeInfo, Encode, Decode)]
+#[derive(Eq, PartialEq, Clone, TypeInfo, Encode, Decode, Debug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum ProposalStatus {
 	/// Voting in progress.
 	VotingInProgress,
@@ -36,4 +569,18 @@ pub enum ProposalStatus {
 	Accepted,
 	/// Proposal is rejected.
 	Rejected,
+	/// Waiting for a decision deposit before it can be decided.
+	Preparing,
+	/// Passing, and accepted once it keeps passing until the end of the confirmation period.
+	Confirming,
+	/// Waiting in the seconding queue before voting starts.
+	Queued,
+	/// Waiting for its invited co-authors to accept.
+	AwaitingCoAuthors,
+	/// Waiting for its prerequisite proposals to be accepted.
+	AwaitingPrerequisites,
+	/// Cancelled as a prerequisite proposal was not accepted.
+	Cancelled,
+	/// Would have passed, but another proposal of its group passed by a wider margin.
+	Superseded,
 }
@@ -1,13 +1,10 @@
 
-//! Autogenerated weights for pallet_proposal
+//! Placeholder weights for pallet_proposal
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-06-27, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `Pankajs-MacBook-Pro-2.local`, CPU: `<UNKNOWN>`
-//! EXECUTION: , WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//! THESE WEIGHTS ARE HAND-WRITTEN ESTIMATES, NOT BENCHMARK RESULTS. Regenerate them with the
+//! command below on reference hardware before relying on them in production.
 
-// Executed Command:
+// Command to regenerate:
 // ./target/release/node-template
 // benchmark
 // pallet
@@ -31,65 +28,834 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn create_proposal() -> Weight;
 	fn vote() -> Weight;
+	fn fast_track() -> Weight;
+	fn extend_deadline() -> Weight;
+	fn set_tie_preference() -> Weight;
+	fn place_decision_deposit() -> Weight;
+	fn second() -> Weight;
+	fn accept_co_authorship() -> Weight;
+	fn propose_amendment() -> Weight;
+	fn vote_amendment() -> Weight;
+	fn edit_proposal() -> Weight;
+	fn archive_proposal() -> Weight;
+	fn close_proposal(v: u32, ) -> Weight;
+	fn close_group(p: u32, v: u32, ) -> Weight;
+	fn close_amendment(v: u32, ) -> Weight;
+	fn launch_most_seconded(q: u32, ) -> Weight;
+	fn cancel_dependent() -> Weight;
 }
 
 /// Weights for pallet_proposal using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Proposal::ActiveProposalCount` (r:1 w:1)
+	/// Proof: `Proposal::ActiveProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Proposal::NextProposalId` (r:1 w:1)
 	/// Proof: `Proposal::NextProposalId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Proposal::SubmissionDeposits` (r:0 w:1)
+	/// Proof: `Proposal::SubmissionDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Proposal::ProposalExpireTime` (r:0 w:1)
 	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CoAuthorInvitations` (r:0 w:1)
+	/// Proof: `Proposal::CoAuthorInvitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Proposal::Proposals` (r:0 w:1)
 	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByOwner` (r:0 w:1)
+	/// Proof: `Proposal::ProposalsByOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByStatus` (r:0 w:1)
+	/// Proof: `Proposal::ProposalsByStatus` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByCreation` (r:0 w:1)
+	/// Proof: `Proposal::ProposalsByCreation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalStatistics` (r:1 w:1)
+	/// Proof: `Proposal::ProposalStatistics` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	fn create_proposal() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `42`
-		//  Estimated: `1527`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 1527)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		//  Measured:  `180`
+		//  Estimated: `3593`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
 	}
 	/// Storage: `Proposal::Proposals` (r:1 w:1)
 	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:2)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ConfirmationEnd` (r:1 w:1)
+	/// Proof: `Proposal::ConfirmationEnd` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CurveCheck` (r:1 w:2)
+	/// Proof: `Proposal::CurveCheck` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByStatus` (r:0 w:2)
+	/// Proof: `Proposal::ProposalsByStatus` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::VotesByAccount` (r:1 w:1)
+	/// Proof: `Proposal::VotesByAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalStatistics` (r:1 w:1)
+	/// Proof: `Proposal::ProposalStatistics` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::TallyCheckpoints` (r:1 w:1)
+	/// Proof: `Proposal::TallyCheckpoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::VoterRoots` (r:0 w:1)
+	/// Proof: `Proposal::VoterRoots` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn vote() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `166`
-		//  Estimated: `3631`
-		// Minimum execution time: 10_000_000 picoseconds.
-		Weight::from_parts(11_000_000, 3631)
+		//  Measured:  `184`
+		//  Estimated: `3649`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 3649)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:2)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn fast_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `198`
+		//  Estimated: `3663`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 3663)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:2)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn extend_deadline() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232`
+		//  Estimated: `3697`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3697)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_tie_preference() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232`
+		//  Estimated: `3697`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 3697)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Proposal::ConfirmationEnd` (r:1 w:1)
+	/// Proof: `Proposal::ConfirmationEnd` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::DecisionDeposits` (r:0 w:1)
+	/// Proof: `Proposal::DecisionDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByStatus` (r:0 w:2)
+	/// Proof: `Proposal::ProposalsByStatus` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn place_decision_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402`
+		//  Estimated: `3867`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 3867)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::SecondingQueue` (r:1 w:1)
+	/// Proof: `Proposal::SecondingQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::QueueRanking` (r:1 w:1)
+	/// Proof: `Proposal::QueueRanking` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:3 w:3)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:2)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByStatus` (r:0 w:2)
+	/// Proof: `Proposal::ProposalsByStatus` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByCreation` (r:0 w:2)
+	/// Proof: `Proposal::ProposalsByCreation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn second() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `8799`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(63_000_000, 8799)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
+	}
+	/// Storage: `Proposal::CoAuthorInvitations` (r:1 w:1)
+	/// Proof: `Proposal::CoAuthorInvitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::SecondingQueue` (r:0 w:1)
+	/// Proof: `Proposal::SecondingQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::QueueRanking` (r:1 w:1)
+	/// Proof: `Proposal::QueueRanking` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:2)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByStatus` (r:0 w:2)
+	/// Proof: `Proposal::ProposalsByStatus` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByCreation` (r:0 w:2)
+	/// Proof: `Proposal::ProposalsByCreation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn accept_co_authorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `398`
+		//  Estimated: `3863`
+		// Minimum execution time: 22_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 3863)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `Proposal::Proposals` (r:1 w:0)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Amendments` (r:1 w:1)
+	/// Proof: `Proposal::Amendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Revisions` (r:1 w:0)
+	/// Proof: `Proposal::Revisions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::AmendmentEnd` (r:1 w:1)
+	/// Proof: `Proposal::AmendmentEnd` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_amendment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `372`
+		//  Estimated: `3837`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 3837)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Proposal::Amendments` (r:1 w:1)
+	/// Proof: `Proposal::Amendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:1 w:0)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn vote_amendment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `283`
+		//  Estimated: `3748`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3748)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Amendments` (r:1 w:0)
+	/// Proof: `Proposal::Amendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Revisions` (r:1 w:1)
+	/// Proof: `Proposal::Revisions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn edit_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `391`
+		//  Estimated: `3856`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 3856)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Proposal::Proposals` (r:1 w:0)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::TallyCheckpoints` (r:1 w:1)
+	/// Proof: `Proposal::TallyCheckpoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn archive_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1411`
+		//  Estimated: `4876`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 4876)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:0)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalGroup` (r:1 w:0)
+	/// Proof: `Proposal::ProposalGroup` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByStatus` (r:0 w:2)
+	/// Proof: `Proposal::ProposalsByStatus` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalStatistics` (r:1 w:1)
+	/// Proof: `Proposal::ProposalStatistics` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::SubmissionDeposits` (r:1 w:1)
+	/// Proof: `Proposal::SubmissionDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::DecisionDeposits` (r:1 w:1)
+	/// Proof: `Proposal::DecisionDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Proposal::Amendments` (r:1 w:1)
+	/// Proof: `Proposal::Amendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ActiveProposalCount` (r:1 w:1)
+	/// Proof: `Proposal::ActiveProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Dependents` (r:1 w:1)
+	/// Proof: `Proposal::Dependents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Prerequisites` (r:0 w:1)
+	/// Proof: `Proposal::Prerequisites` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::PendingCancellations` (r:0 w:1)
+	/// Proof: `Proposal::PendingCancellations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::VoterRoots` (r:0 w:1)
+	/// Proof: `Proposal::VoterRoots` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `v` is `[0, 100]`.
+	fn close_proposal(v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612 + v * (33 ±0)`
+		//  Estimated: `4077 + v * (33 ±0)`
+		// Minimum execution time: 44_000_000 picoseconds.
+		Weight::from_parts(45_000_000, 4077)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+			.saturating_add(Weight::from_parts(0, 33).saturating_mul(v.into()))
+	}
+	/// Storage: `Proposal::Groups` (r:1 w:1)
+	/// Proof: `Proposal::Groups` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalGroup` (r:0 w:8)
+	/// Proof: `Proposal::ProposalGroup` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:8 w:8)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByStatus` (r:0 w:16)
+	/// Proof: `Proposal::ProposalsByStatus` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalStatistics` (r:1 w:1)
+	/// Proof: `Proposal::ProposalStatistics` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::SubmissionDeposits` (r:8 w:8)
+	/// Proof: `Proposal::SubmissionDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::DecisionDeposits` (r:8 w:8)
+	/// Proof: `Proposal::DecisionDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:8 w:8)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:8 w:8)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Proposal::Amendments` (r:8 w:8)
+	/// Proof: `Proposal::Amendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ActiveProposalCount` (r:1 w:1)
+	/// Proof: `Proposal::ActiveProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Dependents` (r:8 w:8)
+	/// Proof: `Proposal::Dependents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Prerequisites` (r:0 w:8)
+	/// Proof: `Proposal::Prerequisites` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::VoterRoots` (r:0 w:8)
+	/// Proof: `Proposal::VoterRoots` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `p` is `[1, 8]`.
+	/// The range of component `v` is `[0, 100]`.
+	fn close_group(p: u32, v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `248 + p * (389 ±0) + v * (33 ±0)`
+		//  Estimated: `3713 + p * (2603 ±0) + v * (33 ±0)`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 3713)
+			// Standard Error: 38_000
+			.saturating_add(Weight::from_parts(39_000_000, 0).saturating_mul(p.into()))
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((11_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 33).saturating_mul(v.into()))
+	}
+	/// Storage: `Proposal::Amendments` (r:1 w:1)
+	/// Proof: `Proposal::Amendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Revisions` (r:1 w:1)
+	/// Proof: `Proposal::Revisions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CandleCheckpoints` (r:0 w:1)
+	/// Proof: `Proposal::CandleCheckpoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::TallyCheckpoints` (r:1 w:1)
+	/// Proof: `Proposal::TallyCheckpoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::VotesByAccount` (r:100 w:100)
+	/// Proof: `Proposal::VotesByAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `v` is `[0, 100]`.
+	fn close_amendment(v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512 + v * (120 ±0)`
+		//  Estimated: `3977 + v * (2595 ±0)`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 3977)
+			// Standard Error: 3_000
+			.saturating_add(Weight::from_parts(3_100_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2595).saturating_mul(v.into()))
+	}
+	/// Storage: `Proposal::QueueRanking` (r:1 w:1)
+	/// Proof: `Proposal::QueueRanking` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::SecondingQueue` (r:1 w:1)
+	/// Proof: `Proposal::SecondingQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:100 w:100)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:2)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByStatus` (r:0 w:2)
+	/// Proof: `Proposal::ProposalsByStatus` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByCreation` (r:0 w:2)
+	/// Proof: `Proposal::ProposalsByCreation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `q` is `[1, 100]`.
+	fn launch_most_seconded(q: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4355 + q * (28 ±0)`
+		//  Estimated: `7820 + q * (28 ±0)`
+		// Minimum execution time: 312_000_000 picoseconds.
+		Weight::from_parts(317_000_000, 7820)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(150_000, 0).saturating_mul(q.into()))
+			.saturating_add(T::DbWeight::get().reads(104_u64))
+			.saturating_add(T::DbWeight::get().writes(109_u64))
+			.saturating_add(Weight::from_parts(0, 28).saturating_mul(q.into()))
+	}
+	/// Storage: `Proposal::PendingCancellations` (r:1 w:1)
+	/// Proof: `Proposal::PendingCancellations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CoAuthorInvitations` (r:0 w:1)
+	/// Proof: `Proposal::CoAuthorInvitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:1)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByStatus` (r:0 w:2)
+	/// Proof: `Proposal::ProposalsByStatus` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalStatistics` (r:1 w:1)
+	/// Proof: `Proposal::ProposalStatistics` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::VoterRoots` (r:0 w:1)
+	/// Proof: `Proposal::VoterRoots` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::SubmissionDeposits` (r:1 w:1)
+	/// Proof: `Proposal::SubmissionDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Proposal::ActiveProposalCount` (r:1 w:1)
+	/// Proof: `Proposal::ActiveProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Prerequisites` (r:0 w:1)
+	/// Proof: `Proposal::Prerequisites` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Dependents` (r:1 w:1)
+	/// Proof: `Proposal::Dependents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_dependent() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `598`
+		//  Estimated: `4063`
+		// Minimum execution time: 39_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 4063)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
+	/// Storage: `Proposal::ActiveProposalCount` (r:1 w:1)
+	/// Proof: `Proposal::ActiveProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Proposal::NextProposalId` (r:1 w:1)
 	/// Proof: `Proposal::NextProposalId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Proposal::SubmissionDeposits` (r:0 w:1)
+	/// Proof: `Proposal::SubmissionDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Proposal::ProposalExpireTime` (r:0 w:1)
 	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CoAuthorInvitations` (r:0 w:1)
+	/// Proof: `Proposal::CoAuthorInvitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Proposal::Proposals` (r:0 w:1)
 	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByOwner` (r:0 w:1)
+	/// Proof: `Proposal::ProposalsByOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByStatus` (r:0 w:1)
+	/// Proof: `Proposal::ProposalsByStatus` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByCreation` (r:0 w:1)
+	/// Proof: `Proposal::ProposalsByCreation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalStatistics` (r:1 w:1)
+	/// Proof: `Proposal::ProposalStatistics` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	fn create_proposal() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `42`
-		//  Estimated: `1527`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 1527)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		//  Measured:  `180`
+		//  Estimated: `3593`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
 	}
 	/// Storage: `Proposal::Proposals` (r:1 w:1)
 	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:2)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ConfirmationEnd` (r:1 w:1)
+	/// Proof: `Proposal::ConfirmationEnd` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CurveCheck` (r:1 w:2)
+	/// Proof: `Proposal::CurveCheck` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByStatus` (r:0 w:2)
+	/// Proof: `Proposal::ProposalsByStatus` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::VotesByAccount` (r:1 w:1)
+	/// Proof: `Proposal::VotesByAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalStatistics` (r:1 w:1)
+	/// Proof: `Proposal::ProposalStatistics` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::TallyCheckpoints` (r:1 w:1)
+	/// Proof: `Proposal::TallyCheckpoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::VoterRoots` (r:0 w:1)
+	/// Proof: `Proposal::VoterRoots` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn vote() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `166`
-		//  Estimated: `3631`
-		// Minimum execution time: 10_000_000 picoseconds.
-		Weight::from_parts(11_000_000, 3631)
+		//  Measured:  `184`
+		//  Estimated: `3649`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 3649)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:2)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn fast_track() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `198`
+		//  Estimated: `3663`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 3663)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:2)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn extend_deadline() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232`
+		//  Estimated: `3697`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3697)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_tie_preference() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `232`
+		//  Estimated: `3697`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 3697)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Proposal::ConfirmationEnd` (r:1 w:1)
+	/// Proof: `Proposal::ConfirmationEnd` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::DecisionDeposits` (r:0 w:1)
+	/// Proof: `Proposal::DecisionDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByStatus` (r:0 w:2)
+	/// Proof: `Proposal::ProposalsByStatus` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn place_decision_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402`
+		//  Estimated: `3867`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 3867)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::SecondingQueue` (r:1 w:1)
+	/// Proof: `Proposal::SecondingQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::QueueRanking` (r:1 w:1)
+	/// Proof: `Proposal::QueueRanking` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:3 w:3)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:2)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByStatus` (r:0 w:2)
+	/// Proof: `Proposal::ProposalsByStatus` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByCreation` (r:0 w:2)
+	/// Proof: `Proposal::ProposalsByCreation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn second() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `8799`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(63_000_000, 8799)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+	}
+	/// Storage: `Proposal::CoAuthorInvitations` (r:1 w:1)
+	/// Proof: `Proposal::CoAuthorInvitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::SecondingQueue` (r:0 w:1)
+	/// Proof: `Proposal::SecondingQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::QueueRanking` (r:1 w:1)
+	/// Proof: `Proposal::QueueRanking` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:2)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByStatus` (r:0 w:2)
+	/// Proof: `Proposal::ProposalsByStatus` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByCreation` (r:0 w:2)
+	/// Proof: `Proposal::ProposalsByCreation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn accept_co_authorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `398`
+		//  Estimated: `3863`
+		// Minimum execution time: 22_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 3863)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `Proposal::Proposals` (r:1 w:0)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Amendments` (r:1 w:1)
+	/// Proof: `Proposal::Amendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Revisions` (r:1 w:0)
+	/// Proof: `Proposal::Revisions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::AmendmentEnd` (r:1 w:1)
+	/// Proof: `Proposal::AmendmentEnd` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_amendment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `372`
+		//  Estimated: `3837`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 3837)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Proposal::Amendments` (r:1 w:1)
+	/// Proof: `Proposal::Amendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:1 w:0)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn vote_amendment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `283`
+		//  Estimated: `3748`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 3748)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Amendments` (r:1 w:0)
+	/// Proof: `Proposal::Amendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Revisions` (r:1 w:1)
+	/// Proof: `Proposal::Revisions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn edit_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `391`
+		//  Estimated: `3856`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 3856)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Proposal::Proposals` (r:1 w:0)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::TallyCheckpoints` (r:1 w:1)
+	/// Proof: `Proposal::TallyCheckpoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn archive_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1411`
+		//  Estimated: `4876`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 4876)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:0)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalGroup` (r:1 w:0)
+	/// Proof: `Proposal::ProposalGroup` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByStatus` (r:0 w:2)
+	/// Proof: `Proposal::ProposalsByStatus` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalStatistics` (r:1 w:1)
+	/// Proof: `Proposal::ProposalStatistics` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::SubmissionDeposits` (r:1 w:1)
+	/// Proof: `Proposal::SubmissionDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::DecisionDeposits` (r:1 w:1)
+	/// Proof: `Proposal::DecisionDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Proposal::Amendments` (r:1 w:1)
+	/// Proof: `Proposal::Amendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ActiveProposalCount` (r:1 w:1)
+	/// Proof: `Proposal::ActiveProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Dependents` (r:1 w:1)
+	/// Proof: `Proposal::Dependents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Prerequisites` (r:0 w:1)
+	/// Proof: `Proposal::Prerequisites` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::PendingCancellations` (r:0 w:1)
+	/// Proof: `Proposal::PendingCancellations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::VoterRoots` (r:0 w:1)
+	/// Proof: `Proposal::VoterRoots` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `v` is `[0, 100]`.
+	fn close_proposal(v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612 + v * (33 ±0)`
+		//  Estimated: `4077 + v * (33 ±0)`
+		// Minimum execution time: 44_000_000 picoseconds.
+		Weight::from_parts(45_000_000, 4077)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+			.saturating_add(Weight::from_parts(0, 33).saturating_mul(v.into()))
+	}
+	/// Storage: `Proposal::Groups` (r:1 w:1)
+	/// Proof: `Proposal::Groups` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalGroup` (r:0 w:8)
+	/// Proof: `Proposal::ProposalGroup` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:8 w:8)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByStatus` (r:0 w:16)
+	/// Proof: `Proposal::ProposalsByStatus` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalStatistics` (r:1 w:1)
+	/// Proof: `Proposal::ProposalStatistics` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::SubmissionDeposits` (r:8 w:8)
+	/// Proof: `Proposal::SubmissionDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::DecisionDeposits` (r:8 w:8)
+	/// Proof: `Proposal::DecisionDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:8 w:8)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:8 w:8)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Proposal::Amendments` (r:8 w:8)
+	/// Proof: `Proposal::Amendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ActiveProposalCount` (r:1 w:1)
+	/// Proof: `Proposal::ActiveProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Dependents` (r:8 w:8)
+	/// Proof: `Proposal::Dependents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Prerequisites` (r:0 w:8)
+	/// Proof: `Proposal::Prerequisites` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::VoterRoots` (r:0 w:8)
+	/// Proof: `Proposal::VoterRoots` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `p` is `[1, 8]`.
+	/// The range of component `v` is `[0, 100]`.
+	fn close_group(p: u32, v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `248 + p * (389 ±0) + v * (33 ±0)`
+		//  Estimated: `3713 + p * (2603 ±0) + v * (33 ±0)`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 3713)
+			// Standard Error: 38_000
+			.saturating_add(Weight::from_parts(39_000_000, 0).saturating_mul(p.into()))
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(1_400_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((7_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((11_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 33).saturating_mul(v.into()))
+	}
+	/// Storage: `Proposal::Amendments` (r:1 w:1)
+	/// Proof: `Proposal::Amendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Revisions` (r:1 w:1)
+	/// Proof: `Proposal::Revisions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CandleCheckpoints` (r:0 w:1)
+	/// Proof: `Proposal::CandleCheckpoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::TallyCheckpoints` (r:1 w:1)
+	/// Proof: `Proposal::TallyCheckpoints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::VotesByAccount` (r:100 w:100)
+	/// Proof: `Proposal::VotesByAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `v` is `[0, 100]`.
+	fn close_amendment(v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512 + v * (120 ±0)`
+		//  Estimated: `3977 + v * (2595 ±0)`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 3977)
+			// Standard Error: 3_000
+			.saturating_add(Weight::from_parts(3_100_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2595).saturating_mul(v.into()))
+	}
+	/// Storage: `Proposal::QueueRanking` (r:1 w:1)
+	/// Proof: `Proposal::QueueRanking` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::SecondingQueue` (r:1 w:1)
+	/// Proof: `Proposal::SecondingQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:100 w:100)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:2)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByStatus` (r:0 w:2)
+	/// Proof: `Proposal::ProposalsByStatus` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByCreation` (r:0 w:2)
+	/// Proof: `Proposal::ProposalsByCreation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `q` is `[1, 100]`.
+	fn launch_most_seconded(q: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4355 + q * (28 ±0)`
+		//  Estimated: `7820 + q * (28 ±0)`
+		// Minimum execution time: 312_000_000 picoseconds.
+		Weight::from_parts(317_000_000, 7820)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(150_000, 0).saturating_mul(q.into()))
+			.saturating_add(RocksDbWeight::get().reads(104_u64))
+			.saturating_add(RocksDbWeight::get().writes(109_u64))
+			.saturating_add(Weight::from_parts(0, 28).saturating_mul(q.into()))
+	}
+	/// Storage: `Proposal::PendingCancellations` (r:1 w:1)
+	/// Proof: `Proposal::PendingCancellations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Proposals` (r:1 w:1)
+	/// Proof: `Proposal::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::CoAuthorInvitations` (r:0 w:1)
+	/// Proof: `Proposal::CoAuthorInvitations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalExpireTime` (r:1 w:1)
+	/// Proof: `Proposal::ProposalExpireTime` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalsByStatus` (r:0 w:2)
+	/// Proof: `Proposal::ProposalsByStatus` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::ProposalStatistics` (r:1 w:1)
+	/// Proof: `Proposal::ProposalStatistics` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::VoterRoots` (r:0 w:1)
+	/// Proof: `Proposal::VoterRoots` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::SubmissionDeposits` (r:1 w:1)
+	/// Proof: `Proposal::SubmissionDeposits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Proposal::ActiveProposalCount` (r:1 w:1)
+	/// Proof: `Proposal::ActiveProposalCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Prerequisites` (r:0 w:1)
+	/// Proof: `Proposal::Prerequisites` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Proposal::Dependents` (r:1 w:1)
+	/// Proof: `Proposal::Dependents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_dependent() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `598`
+		//  Estimated: `4063`
+		// Minimum execution time: 39_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 4063)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
 }
\ No newline at end of file
@@ -102,7 +102,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -225,7 +225,7 @@ impl pallet_balances::Config for Runtime {
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
 	type FreezeIdentifier = ();
 	type MaxFreezes = ();
-	type RuntimeHoldReason = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = ();
 }
 
@@ -248,6 +248,8 @@ impl pallet_sudo::Config for Runtime {
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
+impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+
 /// Configure the pallet-template in pallets/template.
 impl pallet_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
@@ -256,12 +258,102 @@ impl pallet_template::Config for Runtime {
 
 pub type ProposalId = u32;
 
+parameter_types! {
+	pub const ProposalTieBreak: pallet_proposal::types::TieBreak =
+		pallet_proposal::types::TieBreak::Reject;
+	pub const ProposalApprovalCurve: Option<pallet_proposal::types::Curve> =
+		Some(pallet_proposal::types::Curve::Reciprocal {
+			begin: Perbill::from_percent(100),
+			end: Perbill::from_percent(50),
+			factor: 4,
+		});
+}
+
+/// Governance tracks proposals are created on.
+pub struct ProposalTracks;
+impl pallet_proposal::tracks::TracksInfo<AccountId, Balance> for ProposalTracks {
+	type Id = u16;
+	type RuntimeOrigin = RuntimeOrigin;
+
+	fn tracks() -> &'static [(Self::Id, pallet_proposal::tracks::TrackInfo<Balance>)] {
+		static TRACKS: [(u16, pallet_proposal::tracks::TrackInfo<Balance>); 2] = [
+			(
+				0,
+				pallet_proposal::tracks::TrackInfo {
+					name: "signal",
+					submission_deposit: 10 * EXISTENTIAL_DEPOSIT,
+					decision_deposit: 0,
+					min_duration: 1,
+					max_duration: 30,
+					min_turnout: Perbill::zero(),
+					approval_threshold: Perbill::from_percent(50),
+					max_active: 100,
+					confirm_period: 0,
+				},
+			),
+			(
+				1,
+				pallet_proposal::tracks::TrackInfo {
+					name: "treasury",
+					submission_deposit: 100 * EXISTENTIAL_DEPOSIT,
+					decision_deposit: 1_000 * EXISTENTIAL_DEPOSIT,
+					min_duration: 7,
+					max_duration: 30,
+					min_turnout: Perbill::from_percent(10),
+					approval_threshold: Perbill::from_percent(60),
+					max_active: 10,
+					confirm_period: DAYS,
+				},
+			),
+		];
+		&TRACKS
+	}
+
+	fn authorize(
+		_id: Self::Id,
+		origin: Self::RuntimeOrigin,
+	) -> Result<AccountId, sp_runtime::traits::BadOrigin> {
+		frame_system::ensure_signed(origin)
+	}
+}
+
 impl pallet_proposal::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type ProposalId = ProposalId;
 	type NameLimit = ConstU32<256>;
 	type DescriptionLimit = ConstU32<1024>;
 	type AccountLimit = ConstU32<100>;
+	type FastTrackOrigin = frame_system::EnsureRoot<AccountId>;
+	type MinFastTrackPeriod = ConstU32<{ 10 * MINUTES }>;
+	type ExtendOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxExtensions = ConstU32<10>;
+	type Randomness = RandomnessCollectiveFlip;
+	// Every account can vote, so the electorate has no fixed size and no proposal is closed
+	// early on its tally; the approval curve can still pass a proposal before its deadline.
+	type Electorate = ();
+	type TieBreak = ProposalTieBreak;
+	type ApprovalCurve = ProposalApprovalCurve;
+	type SupportCurve = ();
+	type SecondingThreshold = ConstU32<3>;
+	type MaxSeconds = ConstU32<100>;
+	type LaunchPeriod = ConstU32<{ DAYS }>;
+	type QueuePeriod = ConstU32<{ 7 * DAYS }>;
+	type MaxCoAuthors = ConstU32<10>;
+	type InvitationPeriod = ConstU32<{ 7 * DAYS }>;
+	type AmendmentPeriod = ConstU32<{ 2 * DAYS }>;
+	type MinAmendmentVotes = ConstU32<3>;
+	type MaxRevisions = ConstU32<16>;
+	type MaxPrerequisites = ConstU32<8>;
+	type MaxDependents = ConstU32<16>;
+	type MaxGroupSize = ConstU32<8>;
+	type MaxVotesPerAccount = ConstU32<1024>;
+	type MaxCheckpoints = ConstU32<64>;
+	type ArchivePeriod = ConstU32<{ 30 * DAYS }>;
+	type MaxQueued = ConstU32<100>;
+	type MaxCancellations = ConstU32<16>;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Tracks = ProposalTracks;
 	type WeightInfo = pallet_proposal::weights::SubstrateWeight<Runtime>;
 }
 
@@ -309,6 +401,9 @@ mod runtime {
 
 	#[runtime::pallet_index(8)]
 	pub type Proposal = pallet_proposal;
+
+	#[runtime::pallet_index(9)]
+	pub type RandomnessCollectiveFlip = pallet_insecure_randomness_collective_flip;
 }
 
 /// The address format for describing accounts.
@@ -333,7 +428,7 @@ pub type SignedExtra = (
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (pallet_proposal::migrations::v1::MigrateV0ToV1<Runtime>,);
 
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -487,6 +582,78 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_proposal_runtime_api::ProposalApi<Block, AccountId, ProposalId, BlockNumber, u16>
+		for Runtime
+	{
+		fn proposal(
+			proposal_id: ProposalId,
+			voters: pallet_proposal_runtime_api::Page,
+		) -> Option<pallet_proposal_runtime_api::ProposalDetails<AccountId, BlockNumber, u16>> {
+			Proposal::proposal_details(proposal_id, voters)
+		}
+		fn proposal_key(proposal_id: ProposalId) -> Vec<u8> {
+			pallet_proposal::Proposals::<Runtime>::hashed_key_for(proposal_id)
+		}
+		fn active_proposals(
+			page: pallet_proposal_runtime_api::Page,
+		) -> Vec<(ProposalId, pallet_proposal_runtime_api::ProposalDetails<AccountId, BlockNumber, u16>)> {
+			Proposal::active_proposals(page)
+		}
+		fn proposals(
+			filter: pallet_proposal_runtime_api::ProposalFilter<AccountId, u16>,
+			page: pallet_proposal_runtime_api::Page,
+		) -> Vec<(ProposalId, pallet_proposal_runtime_api::ProposalDetails<AccountId, BlockNumber, u16>)> {
+			Proposal::proposals(filter, page)
+		}
+		fn search(
+			query: pallet_proposal_runtime_api::ProposalQuery<AccountId, BlockNumber>,
+			cursor: Option<Vec<u8>>,
+			limit: u32,
+		) -> pallet_proposal_runtime_api::SearchResult<ProposalId, AccountId, BlockNumber, u16> {
+			Proposal::search(query, cursor, limit)
+		}
+		fn votes_of(
+			who: AccountId,
+			page: pallet_proposal_runtime_api::Page,
+		) -> Vec<(ProposalId, pallet_proposal_runtime_api::Vote)> {
+			Proposal::votes_of(&who, page)
+		}
+		fn projected_outcomes(
+			page: pallet_proposal_runtime_api::Page,
+		) -> Vec<(ProposalId, pallet_proposal_runtime_api::Projection)> {
+			Proposal::projected_outcomes(page)
+		}
+		fn validate_vote(
+			proposal_id: ProposalId,
+			who: AccountId,
+			choice: pallet_proposal_runtime_api::Vote,
+		) -> Result<(), Vec<u8>> {
+			Proposal::validate_vote(proposal_id, who, choice)
+		}
+		fn simulate_votes(
+			proposal_id: ProposalId,
+			votes: Vec<(AccountId, pallet_proposal_runtime_api::Vote)>,
+			candle_block: Option<BlockNumber>,
+		) -> Result<pallet_proposal_runtime_api::Simulation, Vec<u8>> {
+			Proposal::simulate_votes(proposal_id, votes, candle_block)
+		}
+		fn tally_checkpoints(proposal_id: ProposalId) -> Vec<(BlockNumber, u32, u32)> {
+			Proposal::tally_checkpoints(proposal_id).into_inner()
+		}
+		fn voter_proof(
+			proposal_id: ProposalId,
+			who: AccountId,
+		) -> Option<pallet_proposal_runtime_api::VoterProof<Hash>> {
+			Proposal::voter_proof(proposal_id, &who)
+		}
+		fn statistics() -> pallet_proposal_runtime_api::Statistics {
+			Proposal::statistics()
+		}
+		fn updates() -> Vec<pallet_proposal_runtime_api::ProposalUpdate<ProposalId, AccountId, u16>> {
+			Proposal::updates()
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,